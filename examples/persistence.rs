@@ -0,0 +1,64 @@
+//! Keeps the chips across restarts in eframe's storage
+
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
+#![allow(rustdoc::missing_crate_level_docs)] // it's an example
+
+use eframe::CreationContext;
+use eframe::egui;
+use egui::Color32;
+use egui_chip::ChipEdit;
+use egui_chip::ChipEditBuilder;
+
+/// The key of the chips in eframe's storage.
+const STORAGE_KEY: &str = "tags";
+
+fn main() -> eframe::Result {
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default().with_inner_size([480., 240.]),
+        ..Default::default()
+    };
+    eframe::run_native(
+        "Persistent egui_chip demo",
+        options,
+        Box::new(|cc| Ok(Box::<MyApp>::new(MyApp::new(cc)))),
+    )
+}
+
+struct MyApp {
+    tags: ChipEdit,
+}
+
+impl MyApp {
+    fn new(cc: &CreationContext) -> Self {
+        let tags = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, STORAGE_KEY))
+            .unwrap_or_else(|| {
+                ChipEditBuilder::new(", ")
+                    .unwrap()
+                    .chip_colors(Color32::DARK_GREEN, Color32::WHITE)
+                    .texts(["rust", "egui", "chips"])
+                    .build()
+            });
+        Self { tags }
+    }
+}
+
+impl eframe::App for MyApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, STORAGE_KEY, &self.tags);
+    }
+
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("My tags");
+            ui.label("Edit the tags and restart the app, they are remembered.");
+            ui.add_space(10.);
+            ui.add(&mut self.tags);
+            ui.add_space(10.);
+            if ui.button("Restore defaults").clicked() {
+                self.tags.restore_defaults();
+            }
+        });
+    }
+}
@@ -6,12 +6,12 @@
 use eframe::CreationContext;
 use eframe::egui;
 use egui::Color32;
-use egui::RichText;
 use egui::TextEdit;
 use egui::color_picker::Alpha;
 use egui::color_picker::color_edit_button_srgba;
 use egui_chip::ChipEdit;
 use egui_chip::ChipEditBuilder;
+use egui_chip::ChipIcon;
 use egui_chip::UnownedChipEdit;
 
 fn main() -> eframe::Result {
@@ -22,13 +22,7 @@ fn main() -> eframe::Result {
     eframe::run_native(
         "Simple egui_chip demo",
         options,
-        Box::new(|cc| {
-            let mut fonts = egui::FontDefinitions::default();
-            egui_nerdfonts::add_to_fonts(&mut fonts, egui_nerdfonts::Variant::Regular);
-
-            cc.egui_ctx.set_fonts(fonts);
-            Ok(Box::<MyApp>::new(MyApp::new(cc)))
-        }),
+        Box::new(|cc| Ok(Box::<MyApp>::new(MyApp::new(cc)))),
     )
 }
 
@@ -50,7 +44,8 @@ impl Configs {
             frame: true,
             chip_size: [60., 20.],
             separator: ";".to_string(),
-            icon: egui_nerdfonts::regular::SMILE.to_owned(),
+            // Part of the emoji font egui ships with.
+            icon: "☺".to_owned(),
             widget_bg: cc.egui_ctx.style().visuals.extreme_bg_color,
             widget_fg: cc.egui_ctx.style().visuals.selection.stroke.color,
             chip_bg: Color32::BLUE,
@@ -69,7 +64,7 @@ impl Configs {
             .chip_icon(if self.icon.is_empty() {
                 None
             } else {
-                Some(RichText::new(&self.icon).weak())
+                Some(ChipIcon::new(&self.icon).weak())
             })
             .unwrap()
     }
@@ -166,11 +161,11 @@ impl eframe::App for MyApp {
                             .range(20.0..=40.0),
                     );
                     ui.end_row();
-                    ui.label("Single char icon");
+                    ui.label("Icon");
                     ui.add(
                         TextEdit::singleline(&mut self.configs.icon)
-                            .char_limit(1)
-                            .desired_width(10.),
+                            .char_limit(ChipIcon::MAX_CHARS)
+                            .desired_width(40.),
                     );
                     ui.end_row();
                 });
@@ -0,0 +1,21 @@
+/// What happens to text the user is still typing when the focus leaves the
+/// widget, see
+/// [`ChipEditBuilder::blur_policy`](crate::ChipEditBuilder::blur_policy).
+///
+/// Text typed between chips becomes a chip being typed right away, which is
+/// committed once the focus moves on. Text stays in the gaps between chips
+/// only in hashtag mode or with a fixed vocabulary, until it forms a chip.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum BlurPolicy {
+    /// The chip being typed is committed, text left in the gaps becomes
+    /// chips, and an edited chip keeps its new text.
+    CommitOnBlur,
+    /// The chip being typed and text left in the gaps are dropped, and an
+    /// edited chip gets back the text it had before the edit.
+    DiscardOnBlur,
+    /// The chip being typed is committed as when the focus moves to another
+    /// chip, while text left in the gaps and edited chips stay as typed, to be
+    /// finished when the focus returns.
+    #[default]
+    KeepEditing,
+}
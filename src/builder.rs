@@ -1,8 +1,42 @@
+use std::fmt::Display;
+use std::hash::Hash;
+use std::str::FromStr;
+use std::sync::Arc;
+
 use egui::Color32;
-use egui::RichText;
+use egui::CornerRadius;
+use egui::Id;
+use egui::ImageSource;
+use egui::Key;
+use egui::KeyboardShortcut;
+use egui::Margin;
+use egui::PointerButton;
+use egui::Response;
+use egui::Shadow;
+use egui::Stroke;
+use egui::Ui;
+use egui::Vec2;
+use egui::WidgetText;
 
+use crate::BlurPolicy;
 use crate::ChipEdit;
+use crate::ChipEditError;
+use crate::ChipEditStyle;
+use crate::ChipIcon;
+use crate::ChipRenderer;
+use crate::ChipShape;
+use crate::ChipSort;
+use crate::ChipStyle;
+use crate::ChipVariant;
+use crate::CommittedChip;
+use crate::Mention;
+use crate::SearchFilter;
+use crate::TypedChipEdit;
 use crate::UnownedChipEdit;
+use crate::email::EMAIL_SEPARATORS;
+use crate::email::is_email;
+use crate::email::split_addresses;
+use crate::hook::Hook;
 
 /// A builder for creating a `ChipEdit` widget with various customization
 /// options.
@@ -25,6 +59,9 @@ use crate::UnownedChipEdit;
 pub struct ChipEditBuilder {
     chip_edit: ChipEdit,
     texts: Vec<String>,
+    /// Whether [`Self::blur_policy`] was called, which
+    /// [`Self::auto_commit_pending`] must not override.
+    blur_policy_set: bool,
 }
 
 impl ChipEditBuilder {
@@ -36,22 +73,146 @@ impl ChipEditBuilder {
     ///
     /// # Errors
     ///
-    /// Returns an error if the separator is empty.
-    pub fn new(separator: &str) -> Result<Self, String> {
+    /// Returns [`ChipEditError::EmptySeparator`] if the separator is empty.
+    pub fn new(separator: &str) -> Result<Self, ChipEditError> {
         if separator.is_empty() {
-            Err("separator cannot be empty".to_owned())
+            Err(ChipEditError::EmptySeparator)
         } else {
             let ret = Self {
                 chip_edit: ChipEdit {
                     texts: vec![],
                     unowned: crate::UnownedChipEdit::new(separator)?,
+                    defaults: vec![],
                 },
                 texts: vec![],
+                blur_policy_set: false,
             };
             Ok(ret)
         }
     }
 
+    /// Creates a builder for a widget whose chips can only be picked from
+    /// `vocabulary`, e.g. a fixed set of labels.
+    ///
+    /// Text typed between chips searches the vocabulary instead of becoming a
+    /// chip, and there is no separator. Chips are not editable; Backspace or
+    /// Delete removes the focused chip or the one next to the cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `vocabulary` - The values that can be picked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    ///
+    /// let chip_edit = ChipEditBuilder::fixed_vocabulary(["bug", "feature", "docs"])
+    ///     .texts(["bug"])
+    ///     .build();
+    /// ```
+    pub fn fixed_vocabulary(vocabulary: impl IntoIterator<Item = impl ToString>) -> Self {
+        // Text is never split in this mode, the separator only joins the
+        // values when the widget is displayed as a string.
+        let mut ret = Self::new(", ").expect("the separator is not empty");
+        ret.chip_edit.unowned.fixed_vocabulary = true;
+        ret.suggestions(vocabulary)
+    }
+
+    /// Creates a builder for a search bar whose chips are filters such as
+    /// `from:alice`, `label:rust` or free text, like Gmail's search box.
+    ///
+    /// Typed text is split into filters by [`SearchFilter::parse_all`] when a
+    /// space is typed outside double quotes, so that `subject:"q3 plan"` stays
+    /// one filter; use [`Self::filter_parser`] to tokenize it differently.
+    /// Read the filters with [`ChipEdit::filters`] to run the query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    /// use egui_chip::SearchFilter;
+    ///
+    /// let search = ChipEditBuilder::search_filters()
+    ///     .texts(["from:alice", "label:rust", "borrow checker"])
+    ///     .build();
+    /// assert_eq!(search.filters()[1], SearchFilter::keyed("label", "rust"));
+    /// ```
+    pub fn search_filters() -> Self {
+        Self::new(" ")
+            .expect("the separator is not empty")
+            .filter_parser(SearchFilter::parse_all)
+    }
+
+    /// Sets how typed text is split into filter chips, e.g. to accept only
+    /// known keys or to expand shorthands like `@alice` into `from:alice`.
+    ///
+    /// Every returned filter becomes a chip holding its [`Display`] text, which
+    /// [`ChipEdit::filters`] reads back with [`SearchFilter::parse`]. This
+    /// replaces the hook set with [`Self::on_commit`]. A separator typed
+    /// between double quotes is kept in the chip rather than committing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `parser` - Called with the committed text, returns its filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    /// use egui_chip::SearchFilter;
+    ///
+    /// let search = ChipEditBuilder::search_filters()
+    ///     .filter_parser(|text| {
+    ///         SearchFilter::parse_all(text)
+    ///             .into_iter()
+    ///             .map(|filter| match filter.value.strip_prefix('@') {
+    ///                 Some(name) if filter.key.is_none() => SearchFilter::keyed("from", name),
+    ///                 _ => filter,
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn filter_parser(
+        mut self,
+        parser: impl Fn(&str) -> Vec<SearchFilter> + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.quotes = true;
+        self.on_commit(move |text| {
+            parser(text)
+                .into_iter()
+                .map(|filter| CommittedChip::new(filter.to_string()))
+                .collect()
+        })
+    }
+
+    /// Creates a builder for a "To:" field of email recipients.
+    ///
+    /// Addresses are split on `,`, `;` and whitespace, whether typed or
+    /// pasted, and malformed ones are drawn in the error color. Read them with
+    /// [`ChipEdit::valid_values`] and [`ChipEdit::invalid_values`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    ///
+    /// let to = ChipEditBuilder::email_mode()
+    ///     .texts(["alice@example.com", "bob@"])
+    ///     .build();
+    /// assert_eq!(to.valid_values(), ["alice@example.com"]);
+    /// assert_eq!(to.invalid_values(), ["bob@"]);
+    /// ```
+    pub fn email_mode() -> Self {
+        Self::new(", ")
+            .expect("the separator is not empty")
+            .typed_separators(EMAIL_SEPARATORS)
+            .paste_separators(EMAIL_SEPARATORS)
+            .on_commit(|text| split_addresses(text).map(CommittedChip::new).collect())
+            .invalid_chip_if(|address| !is_email(address))
+    }
+
     /// Sets the initial texts for the chips.
     ///
     /// # Arguments
@@ -63,6 +224,17 @@ impl ChipEditBuilder {
         self
     }
 
+    /// Sets example chips, e.g. `["rust", "gamedev"]`, shown faded while there
+    /// are no chips. They cannot be edited and disappear once a chip is added.
+    ///
+    /// # Arguments
+    ///
+    /// * `examples` - An iterator of strings representing the example chips.
+    pub fn examples(mut self, examples: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.chip_edit.unowned.examples = examples.into_iter().map(|s| s.to_string()).collect();
+        self
+    }
+
     /// Sets the background and text colors for the chips within `ChipEdit`.
     ///
     /// # Arguments
@@ -70,8 +242,8 @@ impl ChipEditBuilder {
     /// * `bg_color` - The background color for the chips.
     /// * `text_color` - The text color for the chips.
     pub fn chip_colors(mut self, bg_color: Color32, text_color: Color32) -> Self {
-        self.chip_edit.unowned.chip_bg = Some(bg_color);
-        self.chip_edit.unowned.chip_fg = Some(text_color);
+        self.chip_edit.unowned.chip_style.bg_color = Some(bg_color);
+        self.chip_edit.unowned.chip_style.text_color = Some(text_color);
         self
     }
 
@@ -97,6 +269,18 @@ impl ChipEditBuilder {
         self
     }
 
+    /// Sets a source for the ids of the internal editors, so that several chip
+    /// editors in the same window never clash on ids, even with identical
+    /// contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_salt` - A value unique to this widget.
+    pub fn id_salt(mut self, id_salt: impl Hash) -> Self {
+        self.chip_edit.unowned.id = Some(Id::new(id_salt));
+        self
+    }
+
     /// Sets the size of the chips.
     ///
     /// # Arguments
@@ -104,40 +288,1157 @@ impl ChipEditBuilder {
     /// * `chip_size` - An optional array representing the width and height of
     ///   the chips.
     pub fn chip_size(mut self, chip_size: Option<[f32; 2]>) -> Self {
-        self.chip_edit.unowned.chip_size = chip_size;
+        self.chip_edit.unowned.chip_style.size = chip_size;
         self
     }
 
-    /// Sets leading icon for the chips
+    /// Keeps the chips on one row that scrolls horizontally instead of
+    /// wrapping, for toolbar-style inputs with constrained height. The row
+    /// scrolls with the mouse wheel and by dragging its empty area, with
+    /// inertia, and follows the focused chip.
     ///
     /// # Arguments
     ///
-    /// * `char` - A single char text
-    pub fn chip_icon(mut self, icon: Option<RichText>) -> Result<Self, String> {
-        if matches!(&icon, Some(t) if t.text().chars().count() != 1) {
-            Err(format!(
-                "icon text needs to be single char but found {}",
-                icon.unwrap().text().len()
-            ))
-        } else {
-            self.chip_edit.unowned.icon = icon;
-            Ok(self)
+    /// * `single_row` - A boolean indicating whether the chips stay on one row.
+    pub fn single_row(mut self, single_row: bool) -> Self {
+        self.chip_edit.unowned.single_row = single_row;
+        self
+    }
+
+    /// Underlines the first letter or digit of every chip that no earlier chip
+    /// uses. Pressing Alt with that character focuses the chip, or leaves it
+    /// again, while the widget or nothing else in the ui has focus.
+    ///
+    /// # Arguments
+    ///
+    /// * `mnemonics` - Whether the chips have mnemonics.
+    pub fn mnemonics(mut self, mnemonics: bool) -> Self {
+        self.chip_edit.unowned.mnemonics = mnemonics;
+        self
+    }
+
+    /// Sets whether the chips can be edited. A view-only widget shows the
+    /// chips with the disabled visuals of egui, e.g. to reuse the widget on
+    /// screens that only display the values.
+    ///
+    /// # Arguments
+    ///
+    /// * `interactive` - Whether the chips can be edited, deleted and focused.
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.chip_edit.unowned.interactive = interactive;
+        self
+    }
+
+    /// Commits the chip still being typed and text left in the gaps between
+    /// chips when the focus leaves the widget and before
+    /// [`ChipEdit::take_values`], so that nothing the user typed is silently
+    /// dropped when a dialog closes.
+    ///
+    /// The same as [`Self::blur_policy`] with [`BlurPolicy::CommitOnBlur`], or
+    /// [`BlurPolicy::KeepEditing`] when `false`. Does nothing once a policy is
+    /// set with [`Self::blur_policy`], in either order.
+    ///
+    /// # Arguments
+    ///
+    /// * `auto_commit_pending` - A boolean indicating whether pending text is
+    ///   committed automatically.
+    #[deprecated(note = "use `blur_policy` instead")]
+    pub fn auto_commit_pending(mut self, auto_commit_pending: bool) -> Self {
+        if self.blur_policy_set {
+            return self;
         }
+        self.chip_edit.unowned.blur_policy = if auto_commit_pending {
+            BlurPolicy::CommitOnBlur
+        } else {
+            BlurPolicy::KeepEditing
+        };
+        self
     }
 
-    /// Builds the `ChipEdit` widget.
+    /// Sets what happens to text still being typed, between chips or in an
+    /// edited chip, when the focus leaves the widget. Defaults to
+    /// [`BlurPolicy::KeepEditing`].
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// The constructed `ChipEdit` widget.
-    pub fn build(self) -> ChipEdit {
-        let Self {
-            mut chip_edit,
-            texts,
-        } = self;
-        chip_edit.set_text(texts);
-        chip_edit.rebuild();
-        chip_edit
+    /// * `policy` - What to do with the text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::BlurPolicy;
+    /// use egui_chip::ChipEditBuilder;
+    ///
+    /// let filters = ChipEditBuilder::new(",")
+    ///     .unwrap()
+    ///     .blur_policy(BlurPolicy::DiscardOnBlur)
+    ///     .build();
+    /// ```
+    pub fn blur_policy(mut self, policy: BlurPolicy) -> Self {
+        self.chip_edit.unowned.blur_policy = policy;
+        self.blur_policy_set = true;
+        self
+    }
+
+    /// Keeps the chips sorted by `sort` after every change to the chips and
+    /// whenever the focus leaves a chip. The focus follows the focused chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `sort` - The order the chips are kept in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    /// use egui_chip::ChipSort;
+    ///
+    /// let tags = ChipEditBuilder::new(",")
+    ///     .unwrap()
+    ///     .sort(ChipSort::Alphabetical)
+    ///     .build();
+    /// let by_suffix = ChipEditBuilder::new(",")
+    ///     .unwrap()
+    ///     .sort(ChipSort::Custom(|a, b| a.chars().rev().cmp(b.chars().rev())))
+    ///     .build();
+    /// ```
+    pub fn sort(mut self, sort: ChipSort) -> Self {
+        self.chip_edit.unowned.sort = Some(sort);
+        self
+    }
+
+    /// Drops a chip when it is committed as a duplicate of an earlier chip.
+    /// The edited chip is checked once the focus leaves it; locked chips are
+    /// kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `dedup` - Whether duplicate chips are dropped.
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.chip_edit.unowned.dedup = dedup;
+        self
+    }
+
+    /// Drops duplicate chips like [`Self::dedup`], deciding with `eq` which
+    /// chips are the same, e.g. regardless of case so that "Rust" and "rust"
+    /// are one tag. Suggestions that duplicate a chip are not offered either.
+    ///
+    /// # Arguments
+    ///
+    /// * `eq` - Called with the texts of two chips, returns whether they are
+    ///   duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    ///
+    /// let tags = ChipEditBuilder::new(",")
+    ///     .unwrap()
+    ///     .dedup_by(|a, b| a.trim().to_lowercase() == b.trim().to_lowercase())
+    ///     .build();
+    /// ```
+    pub fn dedup_by(mut self, eq: impl Fn(&str, &str) -> bool + Send + Sync + 'static) -> Self {
+        self.chip_edit.unowned.dedup = true;
+        self.chip_edit.unowned.dedup_eq = Some(Hook(Arc::new(eq)));
+        self
+    }
+
+    /// Makes the widget a drop target for `String` payloads, e.g. chips
+    /// dragged from a list with [`egui::Response::dnd_set_drag_payload`].
+    /// While one is dragged over the widget, the frame is highlighted and a
+    /// marker shows where the dropped chip will be inserted.
+    ///
+    /// # Arguments
+    ///
+    /// * `drop_target` - Whether payloads can be dropped on the widget.
+    pub fn drop_target(mut self, drop_target: bool) -> Self {
+        self.chip_edit.unowned.drop_target.enabled = drop_target;
+        self
+    }
+
+    /// Sets the color of the frame and the insertion marker while a payload
+    /// is dragged over a [`Self::drop_target`].
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The highlight color.
+    pub fn drop_highlight(mut self, color: Color32) -> Self {
+        self.chip_edit.unowned.style.drop_highlight = Some(color);
+        self
+    }
+
+    /// Limits the number of chars of all chips together, e.g. for an SMS or a
+    /// list of meta keywords. The chars left are shown below the widget, and
+    /// the editors accept no more text once the budget is used up. Chips added
+    /// otherwise, by accepting a suggestion, dropping or importing text or by
+    /// the app, are cut short to the budget, and not added once it is used
+    /// up.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_total_chars` - The budget in chars, separators excluded.
+    pub fn max_total_chars(mut self, max_total_chars: usize) -> Self {
+        self.chip_edit.unowned.max_total_chars = Some(max_total_chars);
+        self
+    }
+
+    /// Offers an "Import" link below the widget. It opens a window in which
+    /// text can be pasted in bulk; it is split like a paste into the widget,
+    /// with a preview of the number of chips, and appended on confirmation.
+    ///
+    /// # Arguments
+    ///
+    /// * `import` - Whether the link is offered.
+    pub fn import(mut self, import: bool) -> Self {
+        self.chip_edit.unowned.import.enabled = import;
+        self
+    }
+
+    /// Offers a "Show all (N)" link below the widget when the chips exceed
+    /// the maximum height set with [`Self::max_rows`] or [`Self::max_size`].
+    /// The link opens a window in which all chips can be edited.
+    ///
+    /// # Arguments
+    ///
+    /// * `show_all` - Whether the link is offered.
+    pub fn show_all(mut self, show_all: bool) -> Self {
+        self.chip_edit.unowned.show_all = show_all;
+        self
+    }
+
+    /// Picks the date of chips ending with an ISO date, e.g. `2024-05-01` or
+    /// `due:2024-05-01`, from a calendar instead of typing it.
+    ///
+    /// Clicking such a chip, or pressing Enter or Space while it is focused,
+    /// opens a calendar below it; the picked day replaces the date and keeps
+    /// the text before it. Date chips are not edited as text, Backspace or
+    /// Delete removes them.
+    ///
+    /// # Arguments
+    ///
+    /// * `date_picker` - Whether date chips open a calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    ///
+    /// let filters = ChipEditBuilder::search_filters()
+    ///     .date_picker(true)
+    ///     .texts(["from:alice", "due:2024-05-01"])
+    ///     .build();
+    /// ```
+    pub fn date_picker(mut self, date_picker: bool) -> Self {
+        self.chip_edit.unowned.date_picker = date_picker;
+        self
+    }
+
+    /// Shows chips ending with a hex color, e.g. `#ff8800` or `bug:#d73a4a`,
+    /// with a swatch of the color and picks it from a color picker instead of
+    /// typing it, e.g. for a palette or a tag color editor.
+    ///
+    /// Clicking such a chip, or pressing Enter or Space while it is focused,
+    /// opens the picker below it; the picked color replaces the hex color and
+    /// keeps the text before it. Read the colors back with
+    /// [`ChipEdit::color`]. Color chips are not edited as text, Backspace or
+    /// Delete removes them.
+    ///
+    /// # Arguments
+    ///
+    /// * `color_picker` - Whether color chips show a swatch and a picker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui::Color32;
+    /// use egui_chip::ChipEditBuilder;
+    ///
+    /// let palette = ChipEditBuilder::new(" ")
+    ///     .unwrap()
+    ///     .color_picker(true)
+    ///     .texts(["#ff8800", "bug:#d73a4a80"])
+    ///     .build();
+    /// assert_eq!(palette.color(0), Some(Color32::from_rgb(0xff, 0x88, 0x00)));
+    /// ```
+    pub fn color_picker(mut self, color_picker: bool) -> Self {
+        self.chip_edit.unowned.color_picker = color_picker;
+        self
+    }
+
+    /// Draws chips holding a URL, e.g. `https://egui.rs` or `www.egui.rs`, as
+    /// links that open in a new tab on Ctrl+click (Cmd+click on macOS).
+    ///
+    /// A trailing icon set with [`Self::chip_trailing_icon`], e.g. `↗`, also
+    /// opens the link of its chip. A plain click still edits the chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `open_links` - Whether URL chips are drawn and opened as links.
+    pub fn open_links(mut self, open_links: bool) -> Self {
+        self.chip_edit.unowned.open_links = open_links;
+        self
+    }
+
+    /// Keeps typed text in the gaps as free text and commits only words
+    /// starting with `prefix`, e.g. `#rust`, as chips once a space is typed
+    /// after them, like tags in a social post.
+    ///
+    /// The chip keeps the prefix in its text. The rest of the text stays in
+    /// the gaps until
+    /// [`ChipEdit::commit_all_pending`](crate::ChipEdit::commit_all_pending).
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix of the words to commit, e.g. `#`.
+    pub fn hashtags(mut self, prefix: impl Into<String>) -> Self {
+        self.chip_edit.unowned.hashtag_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Adapts the widget to an [`egui::Window`] or popup of constrained width.
+    /// The widget, including [`Self::desired_width`], never gets wider than
+    /// the inner width of its container, so chips wrap instead of growing the
+    /// window. The popups of the widget need nothing extra, they are always
+    /// drawn above windows.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_window` - Whether the widget is shown in a window or popup.
+    pub fn in_window(mut self, in_window: bool) -> Self {
+        self.chip_edit.unowned.in_window = in_window;
+        self
+    }
+
+    /// Sets the width of the widget instead of taking the available width,
+    /// e.g. for a fixed-width inline field.
+    ///
+    /// # Arguments
+    ///
+    /// * `desired_width` - The width of the widget.
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.chip_edit.unowned.desired_width = Some(desired_width);
+        self
+    }
+
+    /// Sets the minimum size of the widget, e.g. to reserve the height of a
+    /// few rows in an auto-height container.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_size` - The minimum width and height.
+    pub fn min_size(mut self, min_size: impl Into<Vec2>) -> Self {
+        self.chip_edit.unowned.min_size = Some(min_size.into());
+        self
+    }
+
+    /// Shrinks the widget to a single row, ignoring [`Self::min_size`], while
+    /// it has no chips, no typed text and no focus. It expands again once it
+    /// gains focus, which keeps sparse forms tidy.
+    ///
+    /// # Arguments
+    ///
+    /// * `shrink_when_empty` - Whether the empty widget shrinks.
+    pub fn shrink_when_empty(mut self, shrink_when_empty: bool) -> Self {
+        self.chip_edit.unowned.shrink_when_empty = shrink_when_empty;
+        self
+    }
+
+    /// Sets the maximum size of the widget. Chips that do not fit the height
+    /// scroll vertically.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_size` - The maximum width and height.
+    pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
+        self.chip_edit.unowned.max_size = Some(max_size.into());
+        self
+    }
+
+    /// Caps the height of the widget at `max_rows` rows of wrapped chips and
+    /// scrolls the chips vertically beyond that, instead of growing and
+    /// pushing the rest of the ui down.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_rows` - The maximum number of visible rows.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.chip_edit.unowned.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Shows only the first `count` chips followed by a `+N` chip while the
+    /// widget does not have focus, for compact places like table cells.
+    /// Clicking the `+N` chip expands the widget until the focus leaves it.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of chips shown while collapsed.
+    pub fn collapse_after(mut self, count: usize) -> Self {
+        self.chip_edit.unowned.collapse_after = Some(count);
+        self
+    }
+
+    /// Skips laying out and painting the units outside the visible clip rect
+    /// and only reserves the space they took in the previous frame, so that
+    /// thousands of chips stay responsive. The focused unit is always shown.
+    ///
+    /// Best used inside a [`egui::ScrollArea`] or with [`Self::single_row`].
+    ///
+    /// # Arguments
+    ///
+    /// * `virtualized` - A boolean indicating whether hidden units are skipped.
+    pub fn virtualized(mut self, virtualized: bool) -> Self {
+        self.chip_edit.unowned.virtualized = virtualized;
+        self
+    }
+
+    /// Shows texts of the form `min..max` as range chips with one editor per
+    /// endpoint. Ranges where `min > max` are drawn with the error color.
+    ///
+    /// Range chips cannot be used with a separator contained in `..`, such as
+    /// `.`.
+    ///
+    /// # Arguments
+    ///
+    /// * `range_chips` - A boolean indicating whether range chips are enabled.
+    pub fn range_chips(mut self, range_chips: bool) -> Self {
+        self.chip_edit.unowned.range_chips = range_chips;
+        self
+    }
+
+    /// Sets the characters that split pasted text into chips in addition to
+    /// the separator. Defaults to newline and tab, so that a column or row
+    /// copied from a spreadsheet pastes as one chip per cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `separators` - The characters to treat as separators when pasting.
+    pub fn paste_separators(mut self, separators: impl IntoIterator<Item = char>) -> Self {
+        self.chip_edit.unowned.paste_separators = separators.into_iter().collect();
+        self
+    }
+
+    /// Sets the characters that commit a chip when typed, like the separator,
+    /// e.g. `;` and space for a list of addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `separators` - The characters to treat as separators when typed.
+    pub fn typed_separators(mut self, separators: impl IntoIterator<Item = char>) -> Self {
+        self.chip_edit.unowned.typed_separators = separators.into_iter().collect();
+        self
+    }
+
+    /// Sets the keys that commit the chip being typed, independently of the
+    /// separator, and go on typing the next chip. Defaults to Enter. Text left
+    /// between chips, e.g. in [`Self::hashtags`] mode, is committed as new
+    /// chips as well.
+    ///
+    /// A key that types a character, e.g. [`Key::Comma`], commits without
+    /// typing it. Tab commits instead of moving to the next widget while there
+    /// is text to commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys that commit typed text, or none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui::Key;
+    /// use egui_chip::ChipEditBuilder;
+    ///
+    /// let tags = ChipEditBuilder::new(",")
+    ///     .unwrap()
+    ///     .commit_keys(&[Key::Enter, Key::Tab, Key::Space])
+    ///     .build();
+    /// ```
+    pub fn commit_keys(mut self, keys: &[Key]) -> Self {
+        self.chip_edit.unowned.commit_keys = keys.to_vec();
+        self
+    }
+
+    /// Rejects typed or pasted characters for which `filter` returns `false`,
+    /// e.g. to only accept digits in a list of ports. The separator is always
+    /// accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Called with every typed or pasted character, returns
+    ///   whether it is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    ///
+    /// let ids = ChipEditBuilder::new(",")
+    ///     .unwrap()
+    ///     .char_filter(|c| c.is_ascii_hexdigit())
+    ///     .build();
+    /// ```
+    pub fn char_filter(mut self, filter: impl Fn(char) -> bool + Send + Sync + 'static) -> Self {
+        self.chip_edit.unowned.char_filter = Some(Hook(Arc::new(filter)));
+        self
+    }
+
+    /// Only accepts the digits `0` to `9` in the chips, e.g. for a list of
+    /// ports. See [`Self::char_filter`].
+    pub fn numeric_only(self) -> Self {
+        self.char_filter(|c| c.is_ascii_digit())
+    }
+
+    /// Sets the values offered in a popup while entering chips.
+    ///
+    /// The popup opens while a chip is being edited, or explicitly with the
+    /// [`Self::suggestion_shortcut`] or ArrowDown in an empty gap, in which
+    /// case it lists all values with the recently used ones first.
+    ///
+    /// # Arguments
+    ///
+    /// * `suggestions` - An iterator of the values to suggest.
+    pub fn suggestions(mut self, suggestions: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.chip_edit.unowned.suggestions.items =
+            suggestions.into_iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Sets the people offered in a popup while typing a mention, e.g. `@al`
+    /// in a chat compose box.
+    ///
+    /// Picking a person turns the chip into a mention showing the trigger and
+    /// the name, e.g. `@Alice`, that keeps the id as metadata. Read the
+    /// mentioned people with [`ChipEdit::mentions`].
+    ///
+    /// # Arguments
+    ///
+    /// * `people` - The people that can be mentioned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    /// use egui_chip::Mention;
+    ///
+    /// let compose = ChipEditBuilder::new(" ")
+    ///     .unwrap()
+    ///     .mentions([Mention::new("u1", "Alice"), Mention::new("u2", "Alan")])
+    ///     .build();
+    /// assert!(compose.mentions().is_empty());
+    /// ```
+    pub fn mentions(mut self, people: impl IntoIterator<Item = Mention>) -> Self {
+        self.chip_edit.unowned.mentions.people = people.into_iter().collect();
+        self
+    }
+
+    /// Sets the character that starts a mention. Defaults to `@`.
+    ///
+    /// # Arguments
+    ///
+    /// * `trigger` - The character typed before a name.
+    pub fn mention_trigger(mut self, trigger: char) -> Self {
+        self.chip_edit.unowned.mentions.trigger = trigger;
+        self
+    }
+
+    /// Orders the suggestions by scores supplied by the app, e.g. usage counts,
+    /// instead of listing the recently used ones first. Suggestions without a
+    /// score rank below all positive scores.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - Pairs of a value and its score, higher scores first.
+    pub fn suggestion_weights(
+        mut self,
+        weights: impl IntoIterator<Item = (impl ToString, f32)>,
+    ) -> Self {
+        self.chip_edit.unowned.set_suggestion_weights(weights);
+        self
+    }
+
+    /// Makes the gap after the last chip claim the remaining width of its row,
+    /// so that clicking anywhere after the chips starts typing a new one.
+    ///
+    /// On by default. A [`Self::single_row`] widget keeps the gap as narrow as
+    /// its text.
+    ///
+    /// # Arguments
+    ///
+    /// * `grow` - Whether the trailing gap fills its row.
+    pub fn grow_trailing_gap(mut self, grow: bool) -> Self {
+        self.chip_edit.unowned.grow_trailing_gap = grow;
+        self
+    }
+
+    /// Lets Ctrl+click (Cmd+click on macOS) add chips to or remove them from a
+    /// multi-selection, drawn in the selection color, for bulk operations such
+    /// as deleting or recoloring several chips. Shift+click selects the chips
+    /// between the last clicked chip and the clicked one, like in a file
+    /// manager. A plain click on a chip clears the selection.
+    ///
+    /// Ctrl+A in a chip whose text is all selected selects all chips, and
+    /// Delete or Backspace removes the selected chips while no text is being
+    /// edited, reporting them in
+    /// [`ChipEditOutput::deleted`](crate::ChipEditOutput::deleted).
+    ///
+    /// Read the selection with
+    /// [`ChipEditOutput::selected`](crate::ChipEditOutput::selected) or
+    /// [`ChipEdit::selected_indices`] and delete it with
+    /// [`ChipEdit::remove_selected`].
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_select` - Whether chips can be selected.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.chip_edit.unowned.selection.enabled = multi_select;
+        self
+    }
+
+    /// Makes Backspace at the start of the text after a chip highlight the
+    /// chip first, and delete it only on a second Backspace, like the
+    /// recipient field of a mail client. Typing or moving the cursor away
+    /// keeps the chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `two_stage` - Whether deleting a chip with Backspace takes two key
+    ///   presses.
+    pub fn two_stage_backspace(mut self, two_stage: bool) -> Self {
+        self.chip_edit.unowned.two_stage_backspace = two_stage;
+        self
+    }
+
+    /// Sets the shortcuts that move the focused chip one position left and
+    /// right, as a keyboard alternative to dragging, e.g. Alt+ArrowLeft and
+    /// Alt+ArrowRight. Neither the focused chip nor the one it would swap
+    /// with may be locked.
+    ///
+    /// Disabled by default, since the editor of the focused chip gives up the
+    /// shortcuts, and Alt+Arrow moves by word in an editor on macOS. `None`
+    /// disables them again.
+    ///
+    /// # Arguments
+    ///
+    /// * `shortcuts` - The shortcuts moving the chip left and right.
+    pub fn move_shortcuts(
+        mut self,
+        shortcuts: Option<(KeyboardShortcut, KeyboardShortcut)>,
+    ) -> Self {
+        self.chip_edit.unowned.move_shortcuts = shortcuts;
+        self
+    }
+
+    /// Sets the shortcut that toggles quick-delete mode. While the mode is
+    /// active, the visible chips are numbered and pressing 1 to 9 deletes the
+    /// corresponding chip; Escape leaves the mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `shortcut` - The keyboard shortcut.
+    pub fn quick_delete_shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.chip_edit.unowned.quick_delete_shortcut = Some(shortcut);
+        self
+    }
+
+    /// Sets a pointer button, e.g. [`PointerButton::Middle`], that removes a
+    /// chip when it clicks it. Removals are reported in
+    /// [`ChipEditOutput::pointer_deleted`](crate::ChipEditOutput::pointer_deleted).
+    /// Locked chips are kept. [`PointerButton::Secondary`] does not combine
+    /// with [`Self::context_menu_fn`].
+    ///
+    /// # Arguments
+    ///
+    /// * `button` - The pointer button.
+    pub fn delete_button(mut self, button: PointerButton) -> Self {
+        self.chip_edit.unowned.delete_button = Some(button);
+        self
+    }
+
+    /// Sets the shortcut that opens the suggestion popup without typed text.
+    /// Defaults to Ctrl+Space.
+    ///
+    /// # Arguments
+    ///
+    /// * `shortcut` - The keyboard shortcut.
+    pub fn suggestion_shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.chip_edit.unowned.suggestions.shortcut = shortcut;
+        self
+    }
+
+    /// Sets a callback that renders a preview, such as a user card or an image
+    /// thumbnail, in a tooltip while a chip is hovered.
+    ///
+    /// # Arguments
+    ///
+    /// * `hover_ui` - Called with the tooltip ui, the index of the hovered chip
+    ///   and its text.
+    pub fn on_hover_ui(
+        mut self,
+        hover_ui: impl Fn(&mut Ui, usize, &str) + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.hover_ui = Some(Hook(Arc::new(hover_ui)));
+        self
+    }
+
+    /// Sets the renderer that draws the chips and the gaps between them, for a
+    /// fully custom skin. The widget keeps handling layout, focus and
+    /// editing.
+    ///
+    /// # Arguments
+    ///
+    /// * `renderer` - The renderer, see [`ChipRenderer`].
+    pub fn renderer(mut self, renderer: impl ChipRenderer + 'static) -> Self {
+        self.chip_edit.unowned.renderer = Some(Hook(Arc::new(renderer)));
+        self
+    }
+
+    /// Sets a callback that draws the content of every chip inside its frame,
+    /// e.g. a progress bar, a two-line label or a badge, instead of its text.
+    /// The widget still lays out, focuses and deletes the chips, but their
+    /// text is no longer edited in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `chip_ui_fn` - Called with the chip ui, the index and text of the chip
+    ///   and whether it has keyboard focus. Returns the response of the
+    ///   content.
+    pub fn chip_ui_fn(
+        mut self,
+        chip_ui_fn: impl Fn(&mut Ui, usize, &str, bool) -> Response + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.chip_ui_fn = Some(Hook(Arc::new(chip_ui_fn)));
+        self
+    }
+
+    /// Sets a callback that renders the menu opened by right-clicking a chip,
+    /// e.g. with "Rename", "Change color" and "Delete" actions. The callback
+    /// cannot change the widget; record the chosen action and apply it, e.g.
+    /// with [`ChipEdit::remove`], after showing the widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `context_menu_fn` - Called with the menu ui, the index of the chip and
+    ///   its text.
+    pub fn context_menu_fn(
+        mut self,
+        context_menu_fn: impl Fn(&mut Ui, usize, &str) + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.context_menu_fn = Some(Hook(Arc::new(context_menu_fn)));
+        self
+    }
+
+    /// Sets a hook that picks the tooltip of a hovered chip, e.g. the full
+    /// email address of a chip showing a display name.
+    ///
+    /// # Arguments
+    ///
+    /// * `tooltip_fn` - Called with the text and index of the hovered chip,
+    ///   returns its tooltip or `None` for no tooltip.
+    pub fn tooltip_fn(
+        mut self,
+        tooltip_fn: impl Fn(&str, usize) -> Option<WidgetText> + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.tooltip_fn = Some(Hook(Arc::new(tooltip_fn)));
+        self
+    }
+
+    /// Sets a predicate that locks chips, so that they can neither be edited
+    /// nor deleted by the user, e.g. for mandatory default tags. Chips can
+    /// also be locked one by one with [`ChipEdit::lock`].
+    ///
+    /// # Arguments
+    ///
+    /// * `locked` - Called with the index and text of a chip, returns whether
+    ///   it is locked.
+    pub fn locked_fn(
+        mut self,
+        locked: impl Fn(usize, &str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.locked_fn = Some(Hook(Arc::new(locked)));
+        self
+    }
+
+    /// Sets a hook that is called when Enter is pressed on a focused chip that
+    /// is not edited as text, e.g. to open the page of a tag: a locked chip, a
+    /// chip of a [`Self::fixed_vocabulary`] or a chip drawn by
+    /// [`Self::chip_ui_fn`]. In a chip edited as text, Enter ends the edit
+    /// instead, and in a date or color chip it opens the picker.
+    ///
+    /// # Arguments
+    ///
+    /// * `activate` - Called with the index and text of the chip.
+    pub fn on_activate(mut self, activate: impl Fn(usize, &str) + Send + Sync + 'static) -> Self {
+        self.chip_edit.unowned.activate_fn = Some(Hook(Arc::new(activate)));
+        self
+    }
+
+    /// Sets a hook that turns committed text into chips, instead of splitting
+    /// it on the separator.
+    ///
+    /// Text typed between chips becomes a chip that is committed once it is
+    /// finished: when a separator is typed in it, one of the
+    /// [`Self::commit_keys`] is pressed or the focus leaves it. So the hook
+    /// sees the whole text rather than its first character. Text pasted
+    /// between chips is committed at once if it holds a separator, and so is
+    /// an edited chip when a separator is typed in it.
+    ///
+    /// The hook may return several chips, each with an optional label and
+    /// metadata, e.g. to turn `John Doe <john@x.com>; Jane <jane@y.com>` into
+    /// two chips labeled with the names that keep the emails as metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit` - Called with the committed text, returns the chips replacing
+    ///   it.
+    pub fn on_commit(
+        mut self,
+        commit: impl Fn(&str) -> Vec<CommittedChip> + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.commit_fn = Some(Hook(Arc::new(commit)));
+        self
+    }
+
+    /// Rewrites the text of every chip when it is committed, by typing a
+    /// separator or by moving the focus out of the chip, so that the values
+    /// need no post-processing.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalize` - Called with the committed text, returns the text kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use egui_chip::ChipEditBuilder;
+    ///
+    /// // "  Game   Dev " becomes "game dev".
+    /// let tags = ChipEditBuilder::new(",")
+    ///     .unwrap()
+    ///     .normalize_fn(|text| {
+    ///         text.split_whitespace()
+    ///             .collect::<Vec<_>>()
+    ///             .join(" ")
+    ///             .to_lowercase()
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn normalize_fn(
+        mut self,
+        normalize: impl Fn(&str) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.normalize_fn = Some(Hook(Arc::new(normalize)));
+        self
+    }
+
+    /// Sets a check that draws the widget frame in the error color while it
+    /// returns `true`, e.g. for "at least one recipient required".
+    ///
+    /// # Arguments
+    ///
+    /// * `check` - Called with the chip values, returns whether they are
+    ///   invalid.
+    pub fn invalid_if(mut self, check: impl Fn(&[String]) -> bool + Send + Sync + 'static) -> Self {
+        self.chip_edit.unowned.invalid_if = Some(Hook(Arc::new(check)));
+        self
+    }
+
+    /// Sets a check that draws a chip in the error color while it returns
+    /// `true`, e.g. for a malformed email address.
+    ///
+    /// # Arguments
+    ///
+    /// * `check` - Called with the text of a chip, returns whether it is
+    ///   invalid.
+    pub fn invalid_chip_if(mut self, check: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        self.chip_edit.unowned.invalid_chip_if = Some(Hook(Arc::new(check)));
+        self
+    }
+
+    /// Sets a hook that picks the colors of every chip, e.g. to color tags by
+    /// category. Takes precedence over [`Self::chip_colors`].
+    ///
+    /// # Arguments
+    ///
+    /// * `color_fn` - Called with the text and index of a chip, returns its
+    ///   background and text colors.
+    pub fn chip_color_fn(
+        mut self,
+        color_fn: impl Fn(&str, usize) -> (Color32, Color32) + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.chip_color_fn = Some(Hook(Arc::new(color_fn)));
+        self
+    }
+
+    /// Sets a hook that picks the leading icon of every chip, e.g. the initials
+    /// of each recipient. Takes precedence over [`Self::chip_icon`].
+    ///
+    /// # Arguments
+    ///
+    /// * `icon_fn` - Called with the text and index of a chip, returns its icon
+    ///   or `None` for no icon.
+    pub fn icon_fn(
+        mut self,
+        icon_fn: impl Fn(&str, usize) -> Option<ChipIcon> + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.icon_fn = Some(Hook(Arc::new(icon_fn)));
+        self
+    }
+
+    /// Sets a hook that picks the leading image of every chip, e.g. the avatar
+    /// of each contact, from a texture or any source egui's image loaders
+    /// handle, such as [`egui::include_image!`]. The image is fitted to the
+    /// chip height, clipped to a circle and shown instead of the icon.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_fn` - Called with the text and index of a chip, returns its
+    ///   image or `None` for no image.
+    pub fn image_fn(
+        mut self,
+        image_fn: impl Fn(&str, usize) -> Option<ImageSource<'static>> + Send + Sync + 'static,
+    ) -> Self {
+        self.chip_edit.unowned.image_fn = Some(Hook(Arc::new(image_fn)));
+        self
+    }
+
+    /// Sets the silhouette of the chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the chips.
+    pub fn chip_shape(mut self, shape: ChipShape) -> Self {
+        self.chip_edit.unowned.chip_style.shape = shape;
+        self
+    }
+
+    /// Sets the look of the widget frame and the layout of the chips, such as
+    /// the frame shadow and the chip spacing. The chips are styled with
+    /// [`Self::style`].
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The style of the widget.
+    pub fn edit_style(mut self, style: ChipEditStyle) -> Self {
+        self.chip_edit.unowned.style = style;
+        self
+    }
+
+    /// Sets the look of the chips, replacing the chip colors, size, shape,
+    /// margins and icon set so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The style of the chips.
+    pub fn style(mut self, style: ChipStyle) -> Self {
+        self.chip_edit.unowned.chip_style = style;
+        self
+    }
+
+    /// Sets the background colors of hovered and pressed chips, instead of
+    /// tints of the chip background color.
+    ///
+    /// # Arguments
+    ///
+    /// * `hover` - The background color while hovered.
+    /// * `pressed` - The background color while pressed.
+    pub fn chip_interaction_colors(mut self, hover: Color32, pressed: Color32) -> Self {
+        self.chip_edit.unowned.chip_style.hover_bg_color = Some(hover);
+        self.chip_edit.unowned.chip_style.pressed_bg_color = Some(pressed);
+        self
+    }
+
+    /// Sets the outline of the chip being edited, instead of the selection
+    /// stroke of the ui.
+    ///
+    /// # Arguments
+    ///
+    /// * `stroke` - The focus ring of the chips.
+    pub fn chip_focus_stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.chip_edit.unowned.chip_style.focus_stroke = Some(stroke.into());
+        self
+    }
+
+    /// Sets the horizontal and vertical gaps between chips, for dense or airy
+    /// layouts. Defaults to no horizontal and one point of vertical spacing.
+    ///
+    /// # Arguments
+    ///
+    /// * `spacing` - The horizontal and vertical gaps.
+    pub fn chip_spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.chip_edit.unowned.style.chip_spacing = spacing.into();
+        self
+    }
+
+    /// Sets the corner radius of rounded chips and of the widget frame, e.g.
+    /// to match a square-corner design system.
+    ///
+    /// # Arguments
+    ///
+    /// * `corner_radius` - The corner radius.
+    pub fn corner_radius(mut self, corner_radius: impl Into<CornerRadius>) -> Self {
+        let corner_radius = corner_radius.into();
+        self.chip_edit.unowned.style.corner_radius = Some(corner_radius);
+        self.chip_edit.unowned.chip_style.corner_radius = Some(corner_radius);
+        self
+    }
+
+    /// Sets the space between the text of a chip and its edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - The inner margin of the chips.
+    pub fn chip_inner_margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.chip_edit.unowned.chip_style.inner_margin = Some(margin.into());
+        self
+    }
+
+    /// Sets the space around every chip, outside its background.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - The outer margin of the chips.
+    pub fn chip_outer_margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.chip_edit.unowned.chip_style.outer_margin = Some(margin.into());
+        self
+    }
+
+    /// Sets the space between the widget frame and the chips it contains.
+    ///
+    /// # Arguments
+    ///
+    /// * `padding` - The padding of the widget frame.
+    pub fn widget_padding(mut self, padding: impl Into<Margin>) -> Self {
+        self.chip_edit.unowned.style.widget_padding = padding.into();
+        self
+    }
+
+    /// Sets the drop shadow painted below every chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `shadow` - The shadow of the chips.
+    pub fn chip_shadow(mut self, shadow: Shadow) -> Self {
+        self.chip_edit.unowned.chip_style.shadow = Some(shadow);
+        self
+    }
+
+    /// Sets the drop shadow painted below the widget frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `shadow` - The shadow of the widget frame.
+    pub fn widget_shadow(mut self, shadow: Shadow) -> Self {
+        self.chip_edit.unowned.style.widget_shadow = Some(shadow);
+        self
+    }
+
+    /// Sets whether the chips are filled, outlined or elevated. Individual
+    /// chips can be changed with [`ChipEdit::set_variant`].
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - The variant of the chips.
+    pub fn variant(mut self, variant: ChipVariant) -> Self {
+        self.chip_edit.unowned.chip_style.variant = variant;
+        self
+    }
+
+    /// Draws all chips with a transparent fill and an outline and text in the
+    /// chip background color, a shorthand for [`Self::variant`].
+    ///
+    /// # Arguments
+    ///
+    /// * `outlined` - Whether the chips are drawn outlined.
+    pub fn outlined(mut self, outlined: bool) -> Self {
+        self.chip_edit.unowned.chip_style.variant = if outlined {
+            ChipVariant::Outlined
+        } else {
+            ChipVariant::Filled
+        };
+        self
+    }
+
+    /// Sets leading icon for the chips
+    ///
+    /// # Arguments
+    ///
+    /// * `icon` - A short icon, e.g. a char, an emoji or a ligature, or `None`
+    ///   for no icon.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChipEditError::InvalidIcon`] if the icon is empty or longer
+    /// than [`ChipIcon::MAX_CHARS`].
+    pub fn chip_icon(mut self, icon: Option<ChipIcon>) -> Result<Self, ChipEditError> {
+        ChipIcon::validate(icon.as_ref())?;
+        self.chip_edit.unowned.chip_style.icon = icon;
+        Ok(self)
+    }
+
+    /// Makes a click on the leading or trailing icon of a chip delete the
+    /// chip, e.g. for a close glyph. Locked chips are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `delete_on_icon_click` - Whether icon clicks delete the chip.
+    pub fn delete_on_icon_click(mut self, delete_on_icon_click: bool) -> Self {
+        self.chip_edit.unowned.delete_on_icon_click = delete_on_icon_click;
+        self
+    }
+
+    /// Sets trailing icon for the chips, e.g. a dropdown arrow or an info
+    /// glyph. Clicks on it are reported in
+    /// [`ChipEditOutput::trailing_icon_clicked`](crate::ChipEditOutput::trailing_icon_clicked)
+    /// instead of editing the chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `icon` - A short icon, or `None` for no icon.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChipEditError::InvalidIcon`] if the icon is empty or longer
+    /// than [`ChipIcon::MAX_CHARS`].
+    pub fn chip_trailing_icon(mut self, icon: Option<ChipIcon>) -> Result<Self, ChipEditError> {
+        ChipIcon::validate(icon.as_ref())?;
+        self.chip_edit.unowned.chip_style.trailing_icon = icon;
+        Ok(self)
+    }
+
+    /// Builds the `ChipEdit` widget.
+    ///
+    /// The texts become the defaults that [`ChipEdit::restore_defaults`]
+    /// returns to.
+    ///
+    /// # Returns
+    ///
+    /// The constructed `ChipEdit` widget.
+    pub fn build(self) -> ChipEdit {
+        let Self {
+            mut chip_edit,
+            mut texts,
+            ..
+        } = self;
+        if let Some(sort) = chip_edit.unowned.sort {
+            texts.sort_by(|a, b| sort.compare(a, b));
+        }
+        chip_edit.defaults = texts.clone();
+        chip_edit.set_text(texts);
+        chip_edit.rebuild();
+        chip_edit
+    }
+
+    /// Builds a [`TypedChipEdit`] whose chips hold values of type `T`, e.g.
+    /// `u32` or `IpAddr`. Chips that do not parse as a `T` are drawn in the
+    /// error color; this replaces any check set with
+    /// [`Self::invalid_chip_if`].
+    ///
+    /// # Returns
+    ///
+    /// The constructed `TypedChipEdit` widget.
+    pub fn build_typed<T: FromStr + Display + 'static>(self) -> TypedChipEdit<T> {
+        self.build().into()
     }
 
     /// Builds the `UnownedChipEdit` widget without texts ownership.
@@ -149,6 +1450,7 @@ impl ChipEditBuilder {
         let Self {
             mut chip_edit,
             texts,
+            ..
         } = self;
         chip_edit.set_text(texts);
         chip_edit.rebuild();
@@ -1,18 +1,121 @@
+use std::ops::RangeInclusive;
+
 use egui::Color32;
+use egui::CornerRadius;
+use egui::Id;
+use egui::Image;
+use egui::ImageSource;
 use egui::Label;
 use egui::Layout;
 use egui::Margin;
+use egui::Rect;
+use egui::Response;
 use egui::RichText;
 use egui::Sense;
+use egui::Shape;
+use egui::Stroke;
+use egui::StrokeKind;
 use egui::TextEdit;
+use egui::TextStyle;
+use egui::TextWrapMode;
 use egui::Ui;
+use egui::Vec2;
+use egui::WidgetText;
+use egui::pos2;
+use egui::text::LayoutJob;
+use egui::text::TextFormat;
+use egui::vec2;
 
+use crate::hook::ChipUiFn;
+use crate::hook::Hook;
 use crate::output::ChipEditOutput;
+use crate::renderer::ChipRenderer;
+use crate::renderer::ChipView;
+use crate::style::ChipStyle;
+use crate::style::ChipVariant;
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub(crate) enum ChipKind {
     Separator,
     Text,
+    /// A numeric `min..max` range with two editable endpoints.
+    Range,
+}
+
+/// Separates the endpoints in the text of a range chip.
+pub(crate) const RANGE_SEPARATOR: &str = "..";
+
+/// Returns whether the first strongly directional character of `text` is
+/// written right to left, as in Arabic or Hebrew.
+pub(crate) fn is_rtl(text: &str) -> bool {
+    text.chars().find(|c| c.is_alphabetic()).is_some_and(|c| {
+        matches!(
+            c as u32,
+            0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF
+        )
+    })
+}
+
+/// Returns `label` with the first occurrence of `mnemonic`, regardless of
+/// case, underlined.
+fn underline_mnemonic(
+    ui: &Ui,
+    label: &str,
+    mnemonic: char,
+    color: Color32,
+    text_style: TextStyle,
+) -> WidgetText {
+    let Some((start, found)) = label
+        .char_indices()
+        .find(|(_, c)| c.eq_ignore_ascii_case(&mnemonic))
+    else {
+        return RichText::new(label)
+            .color(color)
+            .text_style(text_style)
+            .into();
+    };
+    let end = start + found.len_utf8();
+    let format = TextFormat::simple(text_style.resolve(ui.style()), color);
+    let underlined = TextFormat {
+        underline: Stroke::new(1., color),
+        ..format.clone()
+    };
+    let mut job = LayoutJob::default();
+    job.append(&label[..start], 0., format.clone());
+    job.append(&label[start..end], 0., underlined);
+    job.append(&label[end..], 0., format);
+    job.into()
+}
+
+/// Splits `text` into the endpoints of a range chip if both parse as numbers.
+pub(crate) fn range_bounds(text: &str) -> Option<(f64, f64)> {
+    let (min, max) = text.split_once(RANGE_SEPARATOR)?;
+    Some((min.trim().parse().ok()?, max.trim().parse().ok()?))
+}
+
+/// Returns the address `text` links to if it looks like a URL, e.g.
+/// `https://egui.rs` or `www.egui.rs`.
+pub(crate) fn link_url(text: &str) -> Option<String> {
+    let text = text.trim();
+    if text.is_empty() || text.chars().any(char::is_whitespace) {
+        return None;
+    }
+    let rest = ["https://", "http://"]
+        .iter()
+        .find_map(|scheme| text.strip_prefix(scheme));
+    match rest {
+        Some(rest) => (!rest.is_empty()).then(|| text.to_owned()),
+        None => text
+            .strip_prefix("www.")
+            .filter(|rest| rest.contains('.'))
+            .map(|_| format!("https://{text}")),
+    }
+}
+
+/// Parses `text` as a `min..max` range, rejecting ranges where `min > max`.
+pub(crate) fn parse_range(text: &str) -> Option<RangeInclusive<f64>> {
+    let (min, max) = range_bounds(text)?;
+    (min <= max).then_some(min..=max)
 }
 
 fn default_chip_text_color(_ui: &Ui) -> Color32 {
@@ -25,6 +128,24 @@ fn default_chip_bg_color(_ui: &Ui) -> Color32 {
 
 pub(crate) static DEFAULT_CHIP_SIZE: [f32; 2] = [40., 20.];
 
+/// Rounds `points` to the nearest physical pixel so that edges stay crisp at
+/// fractional `pixels_per_point`.
+///
+/// Chip sizes are given in points, which egui already scales with the zoom
+/// factor, so rounding is all that is left to do.
+pub(crate) fn snap_to_pixel(ui: &Ui, points: f32) -> f32 {
+    let pixels_per_point = ui.ctx().pixels_per_point();
+    (points * pixels_per_point).round() / pixels_per_point
+}
+
+/// Gives `edit` the explicit `id`, if any, instead of an automatic one.
+fn with_id(edit: TextEdit<'_>, id: Option<Id>) -> TextEdit<'_> {
+    match id {
+        Some(id) => edit.id(id),
+        None => edit,
+    }
+}
+
 fn default_inner_margin() -> Margin {
     let mut r: Margin = 0.0.into();
     r.right = 3;
@@ -44,11 +165,74 @@ pub(crate) struct Chip {
     pub(crate) at_start: bool,
     pub(crate) at_end: bool,
     pub(crate) kind: ChipKind,
-    pub(crate) bg_color: Option<Color32>,
-    pub(crate) text_color: Option<Color32>,
-    pub(crate) size: Option<[f32; 2]>,
+    /// The look of the chip.
+    #[serde(default)]
+    pub(crate) style: ChipStyle,
+    /// Shown instead of the text while the chip is not edited.
+    #[serde(default)]
+    pub(crate) label: Option<String>,
+    /// App data attached to the chip.
+    #[serde(default)]
+    pub(crate) metadata: Option<String>,
+    /// Set with [`UnownedChipEdit::lock`](crate::UnownedChipEdit::lock).
+    #[serde(default)]
+    pub(crate) locked: bool,
+    /// Whether the chip is locked during this frame, by [`Self::locked`] or
+    /// the app's predicate. Locked chips are neither edited nor deleted.
+    #[serde(skip)]
+    pub(crate) read_only: bool,
+    /// Leading image, e.g. an avatar, shown instead of the icon. Not
+    /// serialized since textures only live as long as the app.
+    #[serde(skip)]
+    pub(crate) image: Option<ImageSource<'static>>,
+    /// The character underlined in the label, which focuses the chip with
+    /// Alt.
+    #[serde(skip)]
+    pub(crate) mnemonic: Option<char>,
+    /// Whether the leading icon or image was clicked during the last `show`.
+    #[serde(skip)]
+    pub(crate) icon_clicked: bool,
+    /// Whether the trailing icon was clicked during the last `show`.
+    #[serde(skip)]
+    pub(crate) trailing_icon_clicked: bool,
+    /// Whether the app's check marks the chip invalid this frame.
+    #[serde(skip)]
+    pub(crate) invalid: bool,
+    /// Draws the content of the chip, given its index, instead of its label
+    /// and editor.
+    #[serde(skip)]
+    pub(crate) content_ui: Option<(Hook<ChipUiFn>, usize)>,
+    /// Whether the chip ends with a date that is picked from a calendar
+    /// instead of typed, this frame.
+    #[serde(skip)]
+    pub(crate) date_picker: bool,
+    /// The color the chip ends with, shown as a swatch and picked instead of
+    /// typed, this frame.
+    #[serde(skip)]
+    pub(crate) swatch: Option<Color32>,
+    /// Whether the chip holds a URL drawn as a link, this frame.
+    #[serde(skip)]
+    pub(crate) link: bool,
+    /// Whether the chip is part of the multi-selection.
+    #[serde(skip)]
+    pub(crate) selected: bool,
+    /// Whether Shift+click selects the chips from this one on.
+    #[serde(skip)]
+    pub(crate) selection_anchor: bool,
+    /// Whether the next Backspace deletes the chip, this frame.
+    #[serde(skip)]
+    pub(crate) armed: bool,
+    /// Whether the chip was started by typing in a gap and is still being
+    /// typed, so that it is committed once it is finished.
+    #[serde(skip)]
+    pub(crate) typing: bool,
+    /// The maximum number of chars the editor accepts, keeping all chips
+    /// within their budget.
     #[serde(skip)]
-    pub(crate) icon: Option<RichText>,
+    pub(crate) char_limit: Option<usize>,
+    /// Whether the editor of a gap claims the remaining width of its row.
+    #[serde(skip)]
+    pub(crate) grow: bool,
 }
 
 impl Chip {
@@ -57,113 +241,543 @@ impl Chip {
             at_start: true,
             at_end: true,
             kind: ChipKind::Separator,
-            bg_color: None,
-            text_color: None,
-            size: Some(DEFAULT_CHIP_SIZE),
-            icon: None,
+            style: ChipStyle::default(),
+            label: None,
+            metadata: None,
+            locked: false,
+            read_only: false,
+            image: None,
+            mnemonic: None,
+            icon_clicked: false,
+            trailing_icon_clicked: false,
+            invalid: false,
+            content_ui: None,
+            date_picker: false,
+            swatch: None,
+            link: false,
+            selected: false,
+            selection_anchor: false,
+            armed: false,
+            typing: false,
+            char_limit: None,
+            grow: false,
         }
     }
 
-    pub fn new_chip(size: Option<[f32; 2]>, icon: Option<RichText>) -> Self {
+    pub fn new_chip(style: ChipStyle) -> Self {
         Chip {
             at_start: false,
             at_end: false,
             kind: ChipKind::Text,
-            bg_color: None,
-            text_color: None,
-            size,
-            icon,
+            style,
+            label: None,
+            metadata: None,
+            locked: false,
+            read_only: false,
+            image: None,
+            mnemonic: None,
+            icon_clicked: false,
+            trailing_icon_clicked: false,
+            invalid: false,
+            content_ui: None,
+            date_picker: false,
+            swatch: None,
+            link: false,
+            selected: false,
+            selection_anchor: false,
+            armed: false,
+            typing: false,
+            char_limit: None,
+            grow: false,
         }
     }
 
-    pub fn show_separator(&mut self, ui: &mut Ui, text: &mut String) -> ChipEditOutput {
-        TextEdit::singleline(text)
+    pub fn show_separator(
+        &mut self,
+        ui: &mut Ui,
+        id: Option<Id>,
+        text: &mut String,
+    ) -> ChipEditOutput {
+        let width = if self.grow {
+            // Keep a gap that wraps to the next row wide enough to hit.
+            let min_width = DEFAULT_CHIP_SIZE[0];
+            ui.available_size_before_wrap().x.max(min_width)
+        } else {
+            0.0
+        };
+        with_id(TextEdit::singleline(text), id)
+            .char_limit(self.char_limit.unwrap_or(usize::MAX))
             .clip_text(true)
-            .desired_width(0.0)
+            .desired_width(width)
             .frame(false)
             .margin(Margin::symmetric(4, 2))
             .show(ui)
             .into()
     }
 
+    fn inner_margin(&self) -> Margin {
+        self.style.inner_margin.unwrap_or_else(default_inner_margin)
+    }
+
+    fn outer_margin(&self) -> Margin {
+        self.style.outer_margin.unwrap_or_else(default_outer_margin)
+    }
+
+    /// Returns whether the chip shows right-to-left text.
+    fn is_rtl(&self, text: &str) -> bool {
+        is_rtl(self.label.as_deref().unwrap_or(text))
+    }
+
+    /// Returns the side of the square an image is fitted to, the height of
+    /// the chip content.
+    fn image_side(&self, ui: &Ui, size: [f32; 2]) -> f32 {
+        if size[1] > 0. {
+            size[1]
+        } else {
+            ui.text_style_height(&self.text_style())
+        }
+    }
+
+    /// Returns the width beyond which an icon is truncated, which keeps room
+    /// for a short ligature or an emoji sequence.
+    fn max_icon_width(&self, ui: &Ui, size: [f32; 2]) -> f32 {
+        3. * self.image_side(ui, size)
+    }
+
+    fn text_style(&self) -> TextStyle {
+        self.style.text_style.clone().unwrap_or(TextStyle::Body)
+    }
+
     pub(crate) fn bg_color(&self, ui: &Ui) -> Color32 {
-        self.bg_color.unwrap_or(default_chip_bg_color(ui))
+        self.style.bg_color.unwrap_or(default_chip_bg_color(ui))
     }
 
     pub(crate) fn text_color(&self, ui: &Ui) -> Color32 {
-        self.text_color.unwrap_or(default_chip_text_color(ui))
+        if self.style.variant == ChipVariant::Outlined {
+            return self.bg_color(ui);
+        }
+        self.style.text_color.unwrap_or(default_chip_text_color(ui))
     }
 
     pub(crate) fn draw_text(
         &mut self,
         ui: &mut Ui,
+        id: Option<Id>,
         focused: bool,
+        size: [f32; 2],
         text: &mut String,
     ) -> ChipEditOutput {
-        let text_color = self.text_color(ui);
+        if let Some((content_ui, index)) = &self.content_ui {
+            // Covers the content with a focusable response, so that the chip
+            // takes part in keyboard navigation like any other.
+            let id = id.unwrap_or_else(|| ui.next_auto_id());
+            let focused = ui.memory(|m| m.has_focus(id));
+            let content = content_ui(ui, *index, text, focused);
+            return ui
+                .interact(content.rect, id, Sense::click())
+                .union(content)
+                .into();
+        }
+        let text_color = if self.invalid || (self.is_range() && parse_range(text).is_none()) {
+            ui.visuals().error_fg_color
+        } else if self.link {
+            ui.visuals().hyperlink_color
+        } else {
+            self.text_color(ui)
+        };
+        let text_style = self.text_style();
+        let align = if self.is_rtl(text) {
+            egui::Align::RIGHT
+        } else {
+            egui::Align::LEFT
+        };
         let mut r = None;
-        if let Some(icon) = &self.icon {
+        let mut swatch = None;
+        let mut label_size = size;
+        if let Some(color) = self.swatch {
+            let side = self.image_side(ui, size);
+            label_size[0] = (label_size[0] - side).max(0.);
+            let (rect, response) = ui.allocate_exact_size(vec2(side, side), Sense::click());
+            let corner_radius = side / 4.;
+            ui.painter().rect_filled(rect, corner_radius, color);
+            ui.painter().rect_stroke(
+                rect,
+                corner_radius,
+                ui.visuals().widgets.noninteractive.bg_stroke,
+                StrokeKind::Inside,
+            );
+            swatch = Some(response);
+        } else if let Some(image) = &self.image {
+            let side = self.image_side(ui, size);
+            label_size[0] = (label_size[0] - side).max(0.);
             r = Some(
                 ui.add(
-                    Label::new(icon.clone().color(text_color))
-                        .halign(egui::Align::Center)
-                        .sense(Sense::click())
-                        .truncate(),
+                    Image::new(image.clone())
+                        .fit_to_exact_size(vec2(side, side))
+                        .corner_radius(side / 2.)
+                        .sense(Sense::click()),
                 ),
             );
+        } else if let Some(icon) = &self.style.icon {
+            let max_width = self.max_icon_width(ui, size);
+            r = Some(
+                ui.allocate_ui(vec2(max_width, ui.available_height()), |ui| {
+                    ui.add(
+                        Label::new(icon.rich_text(text_color))
+                            .halign(egui::Align::Center)
+                            .sense(Sense::click())
+                            .truncate(),
+                    )
+                })
+                .inner,
+            );
         }
-        let mut ret: ChipEditOutput = if focused {
-            TextEdit::singleline(text)
+        // The trailing icon keeps its width when the label is truncated.
+        let trailing_icon = self
+            .style
+            .trailing_icon
+            .as_ref()
+            .map(|icon| WidgetText::from(icon.rich_text(text_color)));
+        let max_icon_width = self.max_icon_width(ui, size);
+        let trailing_width = trailing_icon.as_ref().map_or(0., |icon| {
+            icon.clone()
+                .into_galley(
+                    ui,
+                    Some(TextWrapMode::Extend),
+                    f32::INFINITY,
+                    text_style.clone(),
+                )
+                .size()
+                .x
+                .min(max_icon_width)
+        });
+        label_size[0] = (label_size[0] - trailing_width).max(0.);
+        let mut ret: ChipEditOutput = if focused && self.is_range() {
+            Self::edit_range(ui, id, text_color, self.char_limit, text)
+        } else if focused {
+            with_id(TextEdit::singleline(text), id)
+                .char_limit(self.char_limit.unwrap_or(usize::MAX))
                 .text_color(text_color)
+                .font(text_style)
                 .clip_text(true)
                 .frame(false)
-                .horizontal_align(egui::Align::LEFT)
+                .horizontal_align(align)
                 .vertical_align(egui::Align::TOP)
                 .show(ui)
                 .into()
         } else {
+            let label = if let Some(label) = &self.label {
+                label.clone()
+            } else if self.is_range() {
+                text.replacen(RANGE_SEPARATOR, " – ", 1)
+            } else {
+                text.clone()
+            };
+            let label = match self.mnemonic {
+                Some(mnemonic) => underline_mnemonic(ui, &label, mnemonic, text_color, text_style),
+                None if self.link => RichText::new(label)
+                    .color(text_color)
+                    .text_style(text_style)
+                    .underline()
+                    .into(),
+                None => RichText::new(label)
+                    .color(text_color)
+                    .text_style(text_style)
+                    .into(),
+            };
+            // Not selectable, so that dragging a chip scrolls a single row
+            // rather than selecting its text.
             ui.add_sized(
-                self.size.unwrap_or([0., 0.]),
-                Label::new(RichText::new(text.as_str()).color(text_color))
+                label_size,
+                Label::new(label)
                     .sense(Sense::click())
+                    .selectable(false)
                     .truncate(),
             )
             .into()
         };
-        if let Some(r) = r {
-            ret.response = ret.response.union(r);
+        // The icons are kept out of the chip response, so that clicking them
+        // does not edit the chip.
+        self.icon_clicked = r.is_some_and(|r| r.clicked());
+        self.trailing_icon_clicked = trailing_icon.is_some_and(|icon| {
+            ui.allocate_ui(vec2(trailing_width, ui.available_height()), |ui| {
+                ui.add(
+                    Label::new(icon)
+                        .halign(egui::Align::Center)
+                        .sense(Sense::click())
+                        .truncate(),
+                )
+            })
+            .inner
+            .clicked()
+        });
+        // Unlike the icons, the swatch opens the color picker like the label.
+        if let Some(swatch) = swatch {
+            ret.response = ret.response.union(swatch);
         }
 
         ret
     }
 
-    pub fn show_chip(&mut self, ui: &mut Ui, focused: bool, text: &mut String) -> ChipEditOutput {
-        egui::Frame::new()
-            .corner_radius(8)
-            .fill(self.bg_color(ui))
-            .inner_margin(default_inner_margin())
-            .outer_margin(default_outer_margin())
+    /// Shows one editor per endpoint of a range chip and writes the edits
+    /// back to `text`.
+    fn edit_range(
+        ui: &mut Ui,
+        id: Option<Id>,
+        text_color: Color32,
+        char_limit: Option<usize>,
+        text: &mut String,
+    ) -> ChipEditOutput {
+        let (min, max) = text
+            .split_once(RANGE_SEPARATOR)
+            .unwrap_or((text.as_str(), ""));
+        let (mut min, mut max) = (min.to_owned(), max.to_owned());
+        let width = ((ui.available_width() - 8.) / 2.).max(0.);
+        // Each endpoint gets the chars the joined text has left after the
+        // other endpoint and the separator.
+        let budget = char_limit.map_or(usize::MAX, |limit| {
+            limit.saturating_sub(RANGE_SEPARATOR.chars().count())
+        });
+        let edit = |ui: &mut Ui, endpoint: &mut String, other: &str, salt: &str| {
+            let limit = budget.saturating_sub(other.chars().count());
+            ChipEditOutput::from(
+                with_id(TextEdit::singleline(endpoint), id.map(|id| id.with(salt)))
+                    .char_limit(limit)
+                    .text_color(text_color)
+                    .clip_text(true)
+                    .frame(false)
+                    .desired_width(width)
+                    .show(ui),
+            )
+        };
+
+        let lower = edit(ui, &mut min, &max, "min");
+        ui.label(RichText::new("–").color(text_color));
+        let mut upper = edit(ui, &mut max, &min, "max");
+        upper.cursor_offset = min.chars().count() + RANGE_SEPARATOR.len();
+        // The union keeps the id of the first response, so the endpoint that
+        // has the focus, or just lost it, goes first for the focus checks of
+        // the chip to see it.
+        let upper_leads = upper.response.has_focus()
+            || (upper.response.lost_focus() && !lower.response.has_focus());
+        let (mut ret, other) = if upper_leads {
+            (upper, lower)
+        } else {
+            (lower, upper)
+        };
+        ret.union(other);
+        if ret.response.changed() {
+            *text = format!("{min}{RANGE_SEPARATOR}{max}");
+        }
+        ret
+    }
+
+    /// Returns the size of the chip content in points, or `None` to fit the
+    /// text.
+    pub(crate) fn preferred_size(&self, ui: &Ui) -> Option<[f32; 2]> {
+        self.style
+            .size
+            .map(|[width, height]| [snap_to_pixel(ui, width), snap_to_pixel(ui, height)])
+    }
+
+    /// Returns the chip size clamped to the width of the row, and whether it
+    /// had to be shrunk.
+    fn fitted_size(&self, ui: &Ui) -> (Option<[f32; 2]>, bool) {
+        self.fit(ui, self.preferred_size(ui))
+    }
+
+    /// Returns `size` clamped to the width of the row, and whether it had to
+    /// be shrunk.
+    fn fit(&self, ui: &Ui, size: Option<[f32; 2]>) -> (Option<[f32; 2]>, bool) {
+        let margins = self.inner_margin().sum().x + self.outer_margin().sum().x;
+        let max_width = (ui.max_rect().width() - margins).max(0.);
+        match size {
+            Some([width, height]) if width > max_width => (Some([max_width, height]), true),
+            size => (size, false),
+        }
+    }
+
+    /// Shows the chip in its frame, with content of the preferred `size`.
+    pub fn show_chip(
+        &mut self,
+        ui: &mut Ui,
+        id: Option<Id>,
+        focused: bool,
+        size: Option<[f32; 2]>,
+        text: &mut String,
+    ) -> ChipEditOutput {
+        let (size, overflow) = self.fit(ui, size);
+        let mut inner_margin = self.inner_margin();
+        let slant = self
+            .style
+            .shape
+            .slant(size.map_or(0., |[_, height]| height));
+        inner_margin.left += (slant / 2.).round() as i8;
+        inner_margin.right += (slant / 2.).round() as i8;
+        // Painted once the frame knows its final rect, which pills and slanted
+        // chips depend on.
+        let shadow = ui.painter().add(Shape::Noop);
+        let background = ui.painter().add(Shape::Noop);
+        let frame = egui::Frame::new()
+            .inner_margin(inner_margin)
+            .outer_margin(self.outer_margin())
             .show(ui, |ui| {
-                let layout = Layout::left_to_right(egui::Align::Center);
-                if let Some(size) = self.size {
+                // Right-to-left chips lead with the icon on the right.
+                let layout = if self.is_rtl(text) {
+                    Layout::right_to_left(egui::Align::Center)
+                } else {
+                    Layout::left_to_right(egui::Align::Center)
+                };
+                if let Some(size) = size {
                     ui.allocate_ui_with_layout(size.into(), layout, |ui| {
                         ui.spacing_mut().item_spacing = egui::vec2(0., 1.0);
-                        self.draw_text(ui, focused, text)
+                        self.draw_text(ui, id, focused, size, text)
                     })
                     .inner
                 } else {
-                    ui.with_layout(layout, |ui| self.draw_text(ui, focused, text))
+                    ui.with_layout(layout, |ui| self.draw_text(ui, id, focused, [0., 0.], text))
                         .inner
                 }
-            })
-            .inner
+            });
+        // Cover the whole chip, including its margins.
+        let mut ret: ChipEditOutput = frame.inner;
+        ret.response = ret.response.union(frame.response);
+        let (chip_shadow, chip_background) =
+            self.background(ui, ret.response.rect, Some(&ret.response));
+        if let Some(chip_shadow) = chip_shadow {
+            ui.painter().set(shadow, chip_shadow);
+        }
+        ui.painter().set(background, chip_background);
+        ret.overflow = overflow;
+        ret
     }
 
-    pub fn show(&mut self, ui: &mut Ui, focused: bool, text: &mut String) -> ChipEditOutput {
-        if self.is_separator() {
-            self.show_separator(ui, text)
+    /// Returns the shadow, if any, and the background of a chip covering
+    /// `rect`, reflecting the hover, press and focus state of `response`.
+    fn background(
+        &self,
+        ui: &Ui,
+        rect: Rect,
+        response: Option<&Response>,
+    ) -> (Option<Shape>, Shape) {
+        let corner_radius = self
+            .style
+            .corner_radius
+            .unwrap_or_else(|| CornerRadius::same(8));
+        let shadow = match self.style.variant {
+            ChipVariant::Elevated => Some(self.style.shadow.unwrap_or(ChipVariant::ELEVATION)),
+            ChipVariant::Filled | ChipVariant::Outlined => self.style.shadow,
+        };
+        let shadow = shadow.map(|shadow| {
+            let radius = self.style.shape.corner_radius(rect, corner_radius);
+            shadow.as_shape(rect, radius).into()
+        });
+        let (fill, mut stroke) = if self.style.variant == ChipVariant::Outlined {
+            let stroke = Stroke::new(snap_to_pixel(ui, 1.), self.bg_color(ui));
+            (Color32::TRANSPARENT, self.style.stroke.unwrap_or(stroke))
         } else {
-            self.show_chip(ui, focused, text)
+            (self.bg_color(ui), self.style.stroke.unwrap_or(Stroke::NONE))
+        };
+        let fill = match response {
+            Some(response) if response.is_pointer_button_down_on() => self
+                .style
+                .pressed_bg_color
+                .unwrap_or_else(|| fill.lerp_to_gamma(Color32::BLACK, 0.2)),
+            Some(response) if response.hovered() => self
+                .style
+                .hover_bg_color
+                .unwrap_or_else(|| fill.lerp_to_gamma(Color32::WHITE, 0.15)),
+            _ => fill,
+        };
+        let fill = if self.selected || self.armed {
+            ui.visuals().selection.bg_fill
+        } else {
+            fill
+        };
+        if response.is_some_and(Response::has_focus) {
+            stroke = self
+                .style
+                .focus_stroke
+                .unwrap_or(ui.visuals().selection.stroke);
+        }
+        let background = self.style.shape.paint(rect, corner_radius, fill, stroke);
+        (shadow, background)
+    }
+
+    /// Paints the chip showing `text` without an editor or any interaction,
+    /// for read-only lists of many chips.
+    pub(crate) fn paint_static(&self, ui: &mut Ui, text: &str) -> Response {
+        let (size, _) = self.fitted_size(ui);
+        let label = self.label.as_deref().unwrap_or(text);
+        let rtl = is_rtl(label);
+        let image_side = self
+            .image
+            .as_ref()
+            .map_or(0., |_| self.image_side(ui, size.unwrap_or_default()));
+        let icon = match &self.style.icon {
+            Some(icon) if self.image.is_none() => icon.text.as_str(),
+            _ => "",
+        };
+        let trailing_icon = self
+            .style
+            .trailing_icon
+            .as_ref()
+            .map_or("", |icon| icon.text.as_str());
+        let label = if rtl {
+            format!("{trailing_icon}{label}{icon}")
+        } else {
+            format!("{icon}{label}{trailing_icon}")
+        };
+        let margin = self.inner_margin();
+        let max_width = size.map_or(ui.available_width() - margin.sum().x, |[width, _]| width);
+        let galley = WidgetText::from(label).into_galley(
+            ui,
+            Some(TextWrapMode::Truncate),
+            max_width - image_side,
+            self.text_style(),
+        );
+        let content = size.map_or(galley.size() + vec2(image_side, 0.), Vec2::from);
+        let (rect, response) = ui.allocate_exact_size(content + margin.sum(), Sense::hover());
+        if ui.is_rect_visible(rect) {
+            let (shadow, background) = self.background(ui, rect, None);
+            if let Some(shadow) = shadow {
+                ui.painter().add(shadow);
+            }
+            ui.painter().add(background);
+            let mut pos = rect.center() - galley.size() / 2.;
+            if let Some(image) = &self.image {
+                let inner = rect - margin;
+                let x = if rtl {
+                    inner.right() - image_side
+                } else {
+                    inner.left()
+                };
+                let min = pos2(x, inner.center().y - image_side / 2.);
+                Image::new(image.clone())
+                    .corner_radius(image_side / 2.)
+                    .paint_at(ui, Rect::from_min_size(min, Vec2::splat(image_side)));
+                pos.x += if rtl { -image_side } else { image_side } / 2.;
+            }
+            ui.painter().galley(pos, galley, self.text_color(ui));
+        }
+        response
+    }
+
+    /// Shows the unit with `renderer`.
+    pub fn show(
+        &mut self,
+        renderer: &dyn ChipRenderer,
+        ui: &mut Ui,
+        id: Option<Id>,
+        focused: bool,
+        text: &mut String,
+    ) -> ChipEditOutput {
+        let mut chip = ChipView(self);
+        if chip.0.is_separator() {
+            renderer.draw_separator(ui, &mut chip, id, text)
+        } else {
+            let size = renderer.preferred_size(ui, &chip, text);
+            renderer.draw_chip(ui, &mut chip, id, focused, size, text)
         }
     }
 
@@ -183,4 +797,59 @@ impl Chip {
     pub fn is_separator(&self) -> bool {
         matches!(self.kind, ChipKind::Separator)
     }
+
+    pub fn is_range(&self) -> bool {
+        matches!(self.kind, ChipKind::Range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::CentralPanel;
+    use egui::Context;
+    use egui::Event;
+    use egui::RawInput;
+
+    use super::*;
+
+    #[test]
+    fn range_endpoints_share_the_char_limit() {
+        let ctx = Context::default();
+        let id = Id::new("range");
+        let mut text = "10..20".to_owned();
+        let frames = [vec![], vec![], vec![Event::Text("0000".to_owned())]];
+        for (frame, events) in frames.into_iter().enumerate() {
+            if frame == 1 {
+                ctx.memory_mut(|m| m.request_focus(id.with("min")));
+            }
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        Chip::edit_range(ui, Some(id), Color32::WHITE, Some(8), &mut text)
+                    });
+                });
+            });
+        }
+        assert_eq!(text, "1000..20");
+    }
+
+    #[test]
+    fn links_are_found() {
+        assert_eq!(
+            link_url(" https://egui.rs "),
+            Some("https://egui.rs".to_owned())
+        );
+        assert_eq!(
+            link_url("www.egui.rs"),
+            Some("https://www.egui.rs".to_owned())
+        );
+        assert_eq!(link_url("https://"), None);
+        assert_eq!(link_url("www.egui"), None);
+        assert_eq!(link_url("egui.rs"), None);
+        assert_eq!(link_url("https://egui.rs docs"), None);
+    }
 }
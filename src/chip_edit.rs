@@ -1,28 +1,49 @@
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 
 use egui::Color32;
+use egui::ImageSource;
+use egui::Rect;
 use egui::Response;
 use egui::Ui;
 use egui::Widget;
+use egui::WidgetText;
 
+use crate::BlurPolicy;
+use crate::ChipEditError;
 use crate::ChipEditOutput;
+use crate::ChipIcon;
+use crate::ChipSummary;
+use crate::ChipVariant;
+use crate::LabeledChipEdit;
+use crate::Mention;
+use crate::SearchFilter;
 use crate::UnownedChipEdit;
+use crate::chip::parse_range;
+use crate::color::split_color;
 
 /// Creates a chip style textbox
 ///
 /// Press backspace in empty chip deletes it.
 /// Lost focus from empty chip deletes it.
+///
+/// The whole widget, including colors, sizes, separator texts and focus,
+/// round-trips through serde, so it can be kept in eframe's `Storage` with
+/// `eframe::set_value` and `eframe::get_value`. See the `persistence` example.
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct ChipEdit {
     /// texts
     pub(crate) texts: Vec<String>,
     /// The units (chips) in the widget.
     pub(crate) unowned: UnownedChipEdit,
+    /// The texts the widget was built with, see [`Self::restore_defaults`].
+    #[serde(default)]
+    pub(crate) defaults: Vec<String>,
 }
 
 impl Display for ChipEdit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.values().join(&self.unowned.separator))
+        write!(f, "{}", self.texts.join(&self.unowned.separator))
     }
 }
 
@@ -35,14 +56,15 @@ impl ChipEdit {
     ///
     /// # Errors
     ///
-    /// Returns an error if the separator is empty.
-    pub fn new(separator: &str) -> Result<Self, String> {
+    /// Returns [`ChipEditError::EmptySeparator`] if the separator is empty.
+    pub fn new(separator: &str) -> Result<Self, ChipEditError> {
         if separator.is_empty() {
-            Err("separator cannot be empty".to_owned())
+            Err(ChipEditError::EmptySeparator)
         } else {
             let mut ret = Self {
                 texts: vec![],
                 unowned: UnownedChipEdit::new(separator)?,
+                defaults: vec![],
             };
             ret.rebuild();
             Ok(ret)
@@ -81,6 +103,93 @@ impl ChipEdit {
         self.rebuild();
     }
 
+    /// Replaces the chips with the texts the widget was built with, e.g. to
+    /// reset chips restored from storage.
+    pub fn restore_defaults(&mut self) {
+        self.texts = self.defaults.clone();
+        self.unowned.focused = None;
+        self.rebuild();
+    }
+
+    /// Appends a chip at the end.
+    ///
+    /// The text is cut short to the chars left by
+    /// [`ChipEditBuilder::max_total_chars`](crate::ChipEditBuilder::max_total_chars),
+    /// and no chip is added once they are used up.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text of the new chip.
+    pub fn push(&mut self, text: impl ToString) {
+        self.unowned.push(&mut self.texts, text);
+    }
+
+    /// Inserts a chip at position `index`, shifting all chips after it to the
+    /// right.
+    ///
+    /// The text is cut short to the chars left by
+    /// [`ChipEditBuilder::max_total_chars`](crate::ChipEditBuilder::max_total_chars),
+    /// and no chip is added once they are used up.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the new chip.
+    /// * `text` - The text of the new chip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, text: impl ToString) {
+        self.unowned.insert(&mut self.texts, index, text);
+    }
+
+    /// Removes and returns the chip at position `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip to remove.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> String {
+        self.unowned.remove(&mut self.texts, index)
+    }
+
+    /// Swaps the chips at positions `a` and `b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The position of the first chip.
+    /// * `b` - The position of the second chip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.unowned.swap(&mut self.texts, a, b);
+    }
+
+    /// Removes all chips.
+    pub fn clear(&mut self) {
+        self.unowned.clear(&mut self.texts);
+    }
+
+    /// Returns the number of chips.
+    pub fn len(&self) -> usize {
+        self.texts.len()
+    }
+
+    /// Returns `true` if there are no chips.
+    pub fn is_empty(&self) -> bool {
+        self.texts.is_empty()
+    }
+
+    /// Returns an iterator over the texts of the chips.
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.texts.iter().map(String::as_str)
+    }
+
     /// Returns the current values of the chips.
     ///
     /// # Returns
@@ -90,6 +199,378 @@ impl ChipEdit {
         self.texts.clone()
     }
 
+    /// Returns the filters held by the chips of a
+    /// [`ChipEditBuilder::search_filters`](crate::ChipEditBuilder::search_filters)
+    /// search bar, read with [`SearchFilter::parse`].
+    pub fn filters(&self) -> Vec<SearchFilter> {
+        self.iter().map(SearchFilter::parse).collect()
+    }
+
+    /// Returns the color the chip at `index` ends with, written as `#rrggbb` or
+    /// `#rrggbbaa`, see
+    /// [`ChipEditBuilder::color_picker`](crate::ChipEditBuilder::color_picker).
+    pub fn color(&self, index: usize) -> Option<Color32> {
+        self.texts
+            .get(index)
+            .and_then(|text| split_color(text))
+            .map(|(_, color)| color)
+    }
+
+    /// Returns the values of the chips that pass the check set with
+    /// [`ChipEditBuilder::invalid_chip_if`](crate::ChipEditBuilder::invalid_chip_if),
+    /// e.g. the well-formed addresses of an
+    /// [`ChipEditBuilder::email_mode`](crate::ChipEditBuilder::email_mode)
+    /// field.
+    pub fn valid_values(&self) -> Vec<&str> {
+        self.iter()
+            .filter(|text| !self.unowned.is_invalid_chip(text))
+            .collect()
+    }
+
+    /// Returns the values of the chips drawn in the error color by the check
+    /// set with
+    /// [`ChipEditBuilder::invalid_chip_if`](crate::ChipEditBuilder::invalid_chip_if).
+    pub fn invalid_values(&self) -> Vec<&str> {
+        self.iter()
+            .filter(|text| self.unowned.is_invalid_chip(text))
+            .collect()
+    }
+
+    /// Returns the current values of the chips without cloning them.
+    pub fn values_ref(&self) -> &[String] {
+        &self.texts
+    }
+
+    /// Takes the current values of the chips, leaving the widget empty.
+    ///
+    /// Commits pending text first under [`BlurPolicy::CommitOnBlur`], see
+    /// [`ChipEditBuilder::blur_policy`](crate::ChipEditBuilder::blur_policy).
+    ///
+    /// # Returns
+    ///
+    /// A vector of strings representing the values the chips had.
+    pub fn take_values(&mut self) -> Vec<String> {
+        if self.unowned.blur_policy == BlurPolicy::CommitOnBlur {
+            self.commit_all_pending();
+        }
+        let texts = std::mem::take(&mut self.texts);
+        self.unowned.clear(&mut self.texts);
+        texts
+    }
+
+    /// Commits the chip still being typed and turns any text left in the gaps
+    /// between chips into chips, so that nothing the user typed is dropped or
+    /// left unprocessed by the commit hooks, e.g. when a form is submitted
+    /// while the widget has focus.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any chip was committed or added.
+    pub fn commit_all_pending(&mut self) -> bool {
+        self.unowned.commit_all_pending(&mut self.texts)
+    }
+
+    /// Moves keyboard focus to the chip at `index` on the next `show`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`]. Passing
+    ///   `values().len()` (or anything larger) focuses the trailing input.
+    pub fn focus_chip(&mut self, index: usize) {
+        self.unowned.focus_chip(index);
+    }
+
+    /// Removes keyboard focus from the widget on the next `show`, which deals
+    /// with text still being typed as the [`BlurPolicy`] says.
+    pub fn surrender_focus(&mut self) {
+        self.unowned.surrender_focus();
+    }
+
+    /// Returns the position of the focused chip in [`Self::values`].
+    ///
+    /// Returns `None` when the focus is elsewhere in the ui or in the gap
+    /// between chips.
+    pub fn focused_index(&self) -> Option<usize> {
+        self.unowned.focused_index()
+    }
+
+    /// Draws the chip at `index` as `variant`, overriding
+    /// [`ChipStyle::variant`](crate::ChipStyle::variant) for that chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    /// * `variant` - The variant of the chip.
+    pub fn set_variant(&mut self, index: usize, variant: ChipVariant) {
+        self.unowned.set_variant(index, variant);
+    }
+
+    /// Returns the widget preceded by `label`, for settings forms and grids.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The text shown before the field.
+    pub fn labeled(&mut self, label: impl Into<WidgetText>) -> LabeledChipEdit<'_> {
+        LabeledChipEdit::new(self, label)
+    }
+
+    /// Returns how many more chars the chips may hold before reaching
+    /// [`ChipEditBuilder::max_total_chars`](crate::ChipEditBuilder::max_total_chars),
+    /// or `None` if there is no budget.
+    pub fn chars_left(&self) -> Option<usize> {
+        self.unowned.chars_left(&self.texts)
+    }
+
+    /// Returns a read-only [`ChipSummary`] of the values styled like this
+    /// widget, e.g. to show the tags in a list view.
+    pub fn summary(&self) -> ChipSummary<'_> {
+        self.unowned.summary(&self.texts)
+    }
+
+    /// Locks the chip at `index`, so that it can neither be edited nor
+    /// deleted by the user, e.g. for mandatory tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    pub fn lock(&mut self, index: usize) {
+        self.unowned.lock(index);
+    }
+
+    /// Unlocks the chip at `index` that was locked with [`Self::lock`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    pub fn unlock(&mut self, index: usize) {
+        self.unowned.unlock(index);
+    }
+
+    /// Returns whether the chip at `index` was locked with [`Self::lock`].
+    pub fn is_locked(&self, index: usize) -> bool {
+        self.unowned.is_locked(index)
+    }
+
+    /// Returns the positions of the chips selected with Ctrl+click, in order,
+    /// see [`ChipEditBuilder::multi_select`](crate::ChipEditBuilder::multi_select).
+    pub fn selected_indices(&self) -> Vec<usize> {
+        self.unowned.selected_indices()
+    }
+
+    /// Adds the chip at `index` to the multi-selection, or removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    /// * `selected` - Whether the chip is selected.
+    pub fn set_selected(&mut self, index: usize, selected: bool) {
+        self.unowned.set_selected(index, selected);
+    }
+
+    /// Empties the multi-selection.
+    pub fn clear_selection(&mut self) {
+        self.unowned.clear_selection();
+    }
+
+    /// Removes the selected chips that are not locked, whether with
+    /// [`Self::lock`] or by
+    /// [`ChipEditBuilder::locked_fn`](crate::ChipEditBuilder::locked_fn).
+    ///
+    /// # Returns
+    ///
+    /// The values of the removed chips, in order.
+    pub fn remove_selected(&mut self) -> Vec<String> {
+        self.unowned.remove_selected(&mut self.texts)
+    }
+
+    /// Makes the widget view-only, or editable again. A view-only widget
+    /// shows the chips with the disabled visuals of egui and cannot be
+    /// edited, deleted from or focused.
+    ///
+    /// # Arguments
+    ///
+    /// * `interactive` - Whether the chips can be edited.
+    pub fn set_interactive(&mut self, interactive: bool) {
+        self.unowned.set_interactive(interactive);
+    }
+
+    /// Returns whether the chips can be edited.
+    pub fn is_interactive(&self) -> bool {
+        self.unowned.is_interactive()
+    }
+
+    /// Draws the widget frame as invalid, e.g. after a failed form validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `invalid` - Whether the widget is invalid.
+    pub fn set_invalid(&mut self, invalid: bool) {
+        self.unowned.set_invalid(invalid);
+    }
+
+    /// Returns whether the widget is drawn as invalid, either because of
+    /// [`Self::set_invalid`] or the check set with
+    /// [`ChipEditBuilder::invalid_if`](crate::ChipEditBuilder::invalid_if).
+    pub fn is_invalid(&self) -> bool {
+        self.unowned.is_invalid(&self.texts)
+    }
+
+    /// Returns the label shown instead of the text of the chip at `index`.
+    pub fn label(&self, index: usize) -> Option<&str> {
+        self.unowned.label(index)
+    }
+
+    /// Shows `label` instead of the text of the chip at `index` while it is
+    /// not edited.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    /// * `label` - The label, or `None` to show the text.
+    pub fn set_label(&mut self, index: usize, label: Option<String>) {
+        self.unowned.set_label(index, label);
+    }
+
+    /// Overrides the colors of the chip at `index`, e.g. to highlight it.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    /// * `bg_color` - The background color of the chip.
+    /// * `text_color` - The text color of the chip.
+    pub fn set_chip_colors(&mut self, index: usize, bg_color: Color32, text_color: Color32) {
+        self.unowned.set_chip_colors(index, bg_color, text_color);
+    }
+
+    /// Sets the leading icon of the chip at `index`, e.g. the avatar of a
+    /// recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    /// * `icon` - The icon, or `None` for no icon.
+    pub fn set_icon(&mut self, index: usize, icon: Option<ChipIcon>) {
+        self.unowned.set_icon(index, icon);
+    }
+
+    /// Sets the leading image of the chip at `index`, e.g. the avatar of a
+    /// contact, shown instead of its icon.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    /// * `image` - The image, or `None` for no image.
+    pub fn set_image(&mut self, index: usize, image: Option<ImageSource<'static>>) {
+        self.unowned.set_image(index, image);
+    }
+
+    /// Returns the app data attached to the chip at `index`.
+    pub fn metadata(&self, index: usize) -> Option<&str> {
+        self.unowned.metadata(index)
+    }
+
+    /// Attaches app data to the chip at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    /// * `metadata` - The data, or `None` to remove it.
+    pub fn set_metadata(&mut self, index: usize, metadata: Option<String>) {
+        self.unowned.set_metadata(index, metadata);
+    }
+
+    /// Returns the app data attached to the chip at `index` with
+    /// [`Self::set_typed_metadata`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChipEditError::InvalidMetadata`] if the metadata is not a
+    /// `T`.
+    #[cfg(feature = "typed-metadata")]
+    pub fn typed_metadata<T: serde::de::DeserializeOwned>(
+        &self,
+        index: usize,
+    ) -> Result<Option<T>, ChipEditError> {
+        self.unowned.typed_metadata(index)
+    }
+
+    /// Attaches app data of any serde type to the chip at `index`, persisted
+    /// with the widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in [`Self::values`].
+    /// * `metadata` - The data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChipEditError::InvalidMetadata`] if the data cannot be
+    /// serialized.
+    #[cfg(feature = "typed-metadata")]
+    pub fn set_typed_metadata<T: serde::Serialize>(
+        &mut self,
+        index: usize,
+        metadata: &T,
+    ) -> Result<(), ChipEditError> {
+        self.unowned.set_typed_metadata(index, metadata)
+    }
+
+    /// Sets the values offered in a popup while entering chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `suggestions` - An iterator of the values to suggest.
+    pub fn set_suggestions(&mut self, suggestions: impl IntoIterator<Item = impl ToString>) {
+        self.unowned.set_suggestions(suggestions);
+    }
+
+    /// Returns the people mentioned by the chips, see
+    /// [`ChipEditBuilder::mentions`](crate::ChipEditBuilder::mentions).
+    pub fn mentions(&self) -> Vec<Mention> {
+        self.unowned.mentions(&self.texts)
+    }
+
+    /// Sets the people offered in a popup while typing a mention.
+    ///
+    /// # Arguments
+    ///
+    /// * `people` - The people that can be mentioned.
+    pub fn set_mentions(&mut self, people: impl IntoIterator<Item = Mention>) {
+        self.unowned.set_mentions(people);
+    }
+
+    /// Sets the scores by which suggestions are ordered, e.g. how often or how
+    /// recently each value was used in the app.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - Pairs of a value and its score, higher scores first.
+    pub fn set_suggestion_weights(
+        &mut self,
+        weights: impl IntoIterator<Item = (impl ToString, f32)>,
+    ) {
+        self.unowned.set_suggestion_weights(weights);
+    }
+
+    /// Returns the screen rect of the chip at `index` as laid out during the
+    /// last `show`.
+    ///
+    /// Useful to draw anchors, connecting lines or highlights pointing at a
+    /// chip. Returns `None` if the chip was not shown.
+    pub fn chip_rect(&self, index: usize) -> Option<Rect> {
+        self.unowned.chip_rect(index)
+    }
+
+    /// Returns the numeric range held by the chip at `index`.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the chip does not exist, is not of the form `min..max`, or
+    /// `min > max`.
+    pub fn range(&self, index: usize) -> Option<RangeInclusive<f64>> {
+        self.texts.get(index).and_then(|text| parse_range(text))
+    }
+
     /// Returns the default background color for the widget
     pub fn default_widget_bg_color(ui: &Ui) -> Color32 {
         ui.visuals().extreme_bg_color
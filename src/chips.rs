@@ -0,0 +1,42 @@
+use std::hash::Hash;
+
+use egui::Ui;
+
+use crate::ChipEditOutput;
+use crate::UnownedChipEdit;
+
+/// Separator used by [`chips`].
+const DEFAULT_SEPARATOR: &str = ",";
+
+/// Shows a chip editor for `texts` without holding an [`UnownedChipEdit`]
+/// across frames.
+///
+/// The focus and layout state lives in [`egui::Memory`] under an id derived
+/// from `id_salt`, so the same `id_salt` must be passed every frame. Chips
+/// are separated by `,`; use [`crate::ChipEditBuilder`] for anything more
+/// configurable.
+///
+/// # Arguments
+///
+/// * `ui` - The UI to display the widget in.
+/// * `id_salt` - A source for the id the state is stored under.
+/// * `texts` - A mutable reference to a vector of strings representing the
+///   texts for the chips.
+///
+/// # Returns
+///
+/// A `ChipEditOutput` containing the result of the widget interaction.
+pub fn chips(ui: &mut Ui, id_salt: impl Hash, texts: &mut Vec<String>) -> ChipEditOutput {
+    let id = ui.make_persistent_id(id_salt);
+    let mut edit = ui
+        .data(|d| d.get_temp::<UnownedChipEdit>(id))
+        .unwrap_or_else(|| {
+            let mut edit =
+                UnownedChipEdit::new(DEFAULT_SEPARATOR).expect("default separator is not empty");
+            edit.id = Some(id);
+            edit
+        });
+    let output = edit.show(ui, texts);
+    ui.data_mut(|d| d.insert_temp(id, edit));
+    output
+}
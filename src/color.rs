@@ -0,0 +1,86 @@
+use egui::Color32;
+use egui::Key;
+use egui::PopupCloseBehavior;
+use egui::Ui;
+use egui::color_picker::Alpha;
+use egui::color_picker::color_picker_color32;
+
+use crate::ChipEditOutput;
+
+/// Splits the text of a chip into a prefix, e.g. `bug:`, and the color it
+/// ends with, written as `#rrggbb` or `#rrggbbaa`.
+pub(crate) fn split_color(text: &str) -> Option<(&str, Color32)> {
+    let (prefix, hex) = text.rsplit_once('#')?;
+    if !matches!(hex.len(), 6 | 8) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok();
+    let alpha = if hex.len() == 8 { channel(3)? } else { u8::MAX };
+    let color = Color32::from_rgba_unmultiplied(channel(0)?, channel(1)?, channel(2)?, alpha);
+    Some((prefix, color))
+}
+
+/// Writes `color` as `#rrggbb`, or `#rrggbbaa` if it is not opaque.
+pub(crate) fn to_hex(color: Color32) -> String {
+    match color.to_srgba_unmultiplied() {
+        [r, g, b, u8::MAX] => format!("#{r:02x}{g:02x}{b:02x}"),
+        [r, g, b, a] => format!("#{r:02x}{g:02x}{b:02x}{a:02x}"),
+    }
+}
+
+/// Opens a color picker below a color chip when it is clicked, or focused
+/// and Enter or Space is pressed, and writes the picked color into `text` in
+/// place of its color.
+///
+/// # Arguments
+///
+/// * `ui` - The ui the chip was drawn in.
+/// * `output` - The output of the chip, marked changed while the color is
+///   edited.
+/// * `text` - The text of the chip.
+pub(crate) fn pick_color(ui: &Ui, output: &mut ChipEditOutput, text: &mut String) {
+    let Some((prefix, mut color)) = split_color(text) else {
+        return;
+    };
+    let prefix_len = prefix.len();
+    let popup_id = output.response.id.with("color");
+    if output.response.clicked()
+        || (output.response.has_focus()
+            && ui.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::Space)))
+    {
+        ui.memory_mut(|m| m.toggle_popup(popup_id));
+    }
+    let changed = egui::popup_below_widget(
+        ui,
+        popup_id,
+        &output.response,
+        PopupCloseBehavior::CloseOnClickOutside,
+        |ui| color_picker_color32(ui, &mut color, Alpha::OnlyBlend),
+    )
+    .unwrap_or(false);
+    // The picker stays open while dragging, so every change is committed.
+    if changed {
+        text.replace_range(prefix_len.., &to_hex(color));
+        output.response.mark_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_are_split_off() {
+        assert_eq!(
+            split_color("bug:#ff8000"),
+            Some(("bug:", Color32::from_rgba_unmultiplied(255, 128, 0, 255)))
+        );
+        assert_eq!(
+            split_color("#00000080"),
+            Some(("", Color32::from_rgba_unmultiplied(0, 0, 0, 128)))
+        );
+        assert_eq!(split_color("#fff"), None);
+        assert_eq!(split_color("#gg0000"), None);
+        assert_eq!(split_color("issue #123456x"), None);
+    }
+}
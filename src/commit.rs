@@ -0,0 +1,60 @@
+/// A chip produced when text is committed, e.g. by the hook set with
+/// [`ChipEditBuilder::on_commit`](crate::ChipEditBuilder::on_commit).
+///
+/// # Examples
+///
+/// ```
+/// use egui_chip::ChipEditBuilder;
+/// use egui_chip::CommittedChip;
+///
+/// // "John Doe <john@x.com>" becomes a chip labeled "John Doe" that keeps the
+/// // email as metadata.
+/// let chip_edit = ChipEditBuilder::new(";")
+///     .unwrap()
+///     .on_commit(|text| {
+///         text.split(';')
+///             .map(|entry| match entry.split_once('<') {
+///                 Some((name, email)) => CommittedChip::new(entry.trim())
+///                     .label(name.trim())
+///                     .metadata(email.trim_end_matches('>').trim()),
+///                 None => CommittedChip::new(entry.trim()),
+///             })
+///             .collect()
+///     })
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommittedChip {
+    /// The value of the chip, stored in `texts` and edited in the chip.
+    pub text: String,
+    /// Text shown instead of `text` while the chip is not edited.
+    pub label: Option<String>,
+    /// App data attached to the chip.
+    pub metadata: Option<String>,
+}
+
+impl CommittedChip {
+    /// Creates a chip holding `text`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The value of the chip.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Shows `label` instead of the text while the chip is not edited.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Attaches app data to the chip.
+    pub fn metadata(mut self, metadata: impl Into<String>) -> Self {
+        self.metadata = Some(metadata.into());
+        self
+    }
+}
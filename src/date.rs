@@ -0,0 +1,219 @@
+use std::fmt;
+use std::fmt::Display;
+
+use egui::Grid;
+use egui::Id;
+use egui::Key;
+use egui::PopupCloseBehavior;
+use egui::RichText;
+use egui::Ui;
+
+use crate::ChipEditOutput;
+
+/// Length of an ISO 8601 date, e.g. `2024-05-01`.
+const ISO_LEN: usize = 10;
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A calendar date held by a date chip, written as `YYYY-MM-DD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct IsoDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl IsoDate {
+    /// Parses exactly `YYYY-MM-DD`.
+    fn parse(text: &str) -> Option<Self> {
+        let bytes = text.as_bytes();
+        if bytes.len() != ISO_LEN || bytes[4] != b'-' || bytes[7] != b'-' {
+            return None;
+        }
+        let number = |range: std::ops::Range<usize>| {
+            let digits = &text[range];
+            digits
+                .bytes()
+                .all(|b| b.is_ascii_digit())
+                .then(|| digits.parse::<u32>().ok())
+                .flatten()
+        };
+        let year = number(0..4)? as i32;
+        let month = number(5..7)?;
+        let day = number(8..10)?;
+        ((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day))
+            .then_some(Self { year, month, day })
+    }
+
+    /// Returns the day of the week of the first of the month, 0 for Monday.
+    fn first_weekday(year: i32, month: u32) -> u32 {
+        // Sakamoto's method, 0 for Sunday.
+        const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let year = if month < 3 { year - 1 } else { year };
+        let sunday_based =
+            (year + year / 4 - year / 100 + year / 400 + OFFSETS[month as usize - 1] + 1)
+                .rem_euclid(7);
+        (sunday_based as u32 + 6) % 7
+    }
+}
+
+impl Display for IsoDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Splits the text of a chip into a prefix, e.g. `due:`, and the ISO date
+/// it ends with.
+pub(crate) fn split_date(text: &str) -> Option<(&str, IsoDate)> {
+    let start = text.len().checked_sub(ISO_LEN)?;
+    if !text.is_char_boundary(start) {
+        return None;
+    }
+    let (prefix, date) = text.split_at(start);
+    IsoDate::parse(date).map(|date| (prefix, date))
+}
+
+/// Opens the calendar of a date chip when it is clicked, or focused and
+/// Enter or Space is pressed, and writes the picked day into `text` in place
+/// of its date.
+///
+/// # Arguments
+///
+/// * `ui` - The ui the chip was drawn in.
+/// * `output` - The output of the chip, marked changed when a day is picked.
+/// * `text` - The text of the chip.
+pub(crate) fn pick_date(ui: &Ui, output: &mut ChipEditOutput, text: &mut String) {
+    let Some((prefix, date)) = split_date(text) else {
+        return;
+    };
+    let prefix_len = prefix.len();
+    let popup_id = output.response.id.with("calendar");
+    if output.response.clicked()
+        || (output.response.has_focus()
+            && ui.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::Space)))
+    {
+        // Start at the month of the chip rather than the one last paged to.
+        ui.data_mut(|d| d.insert_temp(popup_id, (date.year, date.month)));
+        ui.memory_mut(|m| m.toggle_popup(popup_id));
+    }
+    let picked = egui::popup_below_widget(
+        ui,
+        popup_id,
+        &output.response,
+        PopupCloseBehavior::CloseOnClickOutside,
+        |ui| calendar_ui(ui, popup_id, date),
+    )
+    .flatten();
+    if let Some(picked) = picked {
+        text.replace_range(prefix_len.., &picked.to_string());
+        output.response.mark_changed();
+        ui.memory_mut(|m| m.close_popup());
+    }
+}
+
+/// Shows a month of `date` as a grid of days, with buttons to page through
+/// the months, and returns the day the user picked, if any.
+///
+/// The shown month is kept in the memory of `id`.
+fn calendar_ui(ui: &mut Ui, id: Id, date: IsoDate) -> Option<IsoDate> {
+    let (mut year, mut month) = ui
+        .data(|d| d.get_temp(id))
+        .unwrap_or((date.year, date.month));
+    let mut picked = None;
+    ui.horizontal(|ui| {
+        if ui.small_button("◀").clicked() {
+            (year, month) = if month == 1 {
+                (year - 1, 12)
+            } else {
+                (year, month - 1)
+            };
+        }
+        ui.label(RichText::new(format!("{} {year}", MONTHS[month as usize - 1])).strong());
+        if ui.small_button("▶").clicked() {
+            (year, month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+        }
+    });
+    Grid::new(id.with("days")).num_columns(7).show(ui, |ui| {
+        for weekday in WEEKDAYS {
+            ui.label(RichText::new(weekday).weak());
+        }
+        ui.end_row();
+        let first = IsoDate::first_weekday(year, month);
+        for _ in 0..first {
+            ui.label("");
+        }
+        for day in 1..=days_in_month(year, month) {
+            let this = IsoDate { year, month, day };
+            if ui.selectable_label(this == date, day.to_string()).clicked() {
+                picked = Some(this);
+            }
+            if (first + day).is_multiple_of(7) {
+                ui.end_row();
+            }
+        }
+    });
+    ui.data_mut(|d| d.insert_temp(id, (year, month)));
+    picked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_valid_dates_parse() {
+        assert_eq!(
+            IsoDate::parse("2024-02-29"),
+            Some(IsoDate {
+                year: 2024,
+                month: 2,
+                day: 29
+            })
+        );
+        assert_eq!(IsoDate::parse("2023-02-29"), None);
+        assert_eq!(IsoDate::parse("2024-13-01"), None);
+        assert_eq!(IsoDate::parse("2024-1-01"), None);
+        assert_eq!(IsoDate::parse("+024-01-01"), None);
+        assert_eq!(
+            split_date("due:2024-05-01").map(|(prefix, _)| prefix),
+            Some("due:")
+        );
+    }
+
+    #[test]
+    fn first_weekday_starts_on_monday() {
+        // 2024-01-01 was a Monday, 2024-09-01 a Sunday.
+        assert_eq!(IsoDate::first_weekday(2024, 1), 0);
+        assert_eq!(IsoDate::first_weekday(2024, 9), 6);
+        assert_eq!(IsoDate::first_weekday(2000, 3), 2);
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use egui::Align;
+use egui::Color32;
+use egui::Direction;
+use egui::Layout;
+use egui::Response;
+use egui::Ui;
+use egui::Widget;
+
+use crate::chip::Chip;
+use crate::style::ChipEditStyle;
+use crate::style::ChipStyle;
+
+/// A read-only view of the changes between two lists of chips.
+///
+/// Shows the chips of the new list, colored by whether they were added or
+/// kept, followed by the chips that were removed from the old list. A chip
+/// that appears more often in one list than in the other counts as added or
+/// removed that many times.
+///
+/// # Examples
+///
+/// ```
+/// use egui_chip::ChipDiff;
+///
+/// let old = vec!["bug".to_owned(), "ui".to_owned()];
+/// let new = vec!["bug".to_owned(), "docs".to_owned()];
+/// # egui::__run_test_ui(|ui| {
+/// // "bug" is unchanged, "docs" added and "ui" removed.
+/// ui.add(ChipDiff::new(&old, &new));
+/// # });
+/// ```
+pub struct ChipDiff<'a> {
+    old: &'a [String],
+    new: &'a [String],
+    added: Color32,
+    removed: Color32,
+    unchanged: Color32,
+}
+
+impl<'a> ChipDiff<'a> {
+    /// Creates the diff from `old` to `new`.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The texts of the chips before the change.
+    /// * `new` - The texts of the chips after the change.
+    pub fn new(old: &'a [String], new: &'a [String]) -> Self {
+        Self {
+            old,
+            new,
+            added: Color32::DARK_GREEN,
+            removed: Color32::DARK_RED,
+            unchanged: Color32::GRAY,
+        }
+    }
+
+    /// Sets the background color of chips only in the new list.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of added chips.
+    pub fn added_color(mut self, color: Color32) -> Self {
+        self.added = color;
+        self
+    }
+
+    /// Sets the background color of chips only in the old list.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of removed chips.
+    pub fn removed_color(mut self, color: Color32) -> Self {
+        self.removed = color;
+        self
+    }
+
+    /// Sets the background color of chips in both lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of unchanged chips.
+    pub fn unchanged_color(mut self, color: Color32) -> Self {
+        self.unchanged = color;
+        self
+    }
+}
+
+impl Widget for ChipDiff<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let layout = Layout::from_main_dir_and_cross_align(Direction::LeftToRight, Align::TOP)
+            .with_main_wrap(true);
+        let mut chip = Chip::new_chip(ChipStyle::default());
+        ui.with_layout(layout, |ui| {
+            ui.spacing_mut().item_spacing = ChipEditStyle::default().chip_spacing;
+            for (text, added) in self.new.iter().zip(missing(self.new, self.old)) {
+                chip.style.bg_color = Some(if added { self.added } else { self.unchanged });
+                chip.paint_static(ui, text);
+            }
+            chip.style.bg_color = Some(self.removed);
+            for (text, removed) in self.old.iter().zip(missing(self.old, self.new)) {
+                if removed {
+                    chip.paint_static(ui, text);
+                }
+            }
+        })
+        .response
+    }
+}
+
+/// Returns for every text of `texts` whether it is missing from `other`,
+/// matching every text of `other` at most once, so that duplicates count.
+fn missing(texts: &[String], other: &[String]) -> Vec<bool> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for text in other {
+        *counts.entry(text).or_default() += 1;
+    }
+    texts
+        .iter()
+        .map(|text| match counts.get_mut(text.as_str()) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|text| text.to_string()).collect()
+    }
+
+    #[test]
+    fn missing_counts_duplicates() {
+        let old = texts(&["bug", "ui", "bug"]);
+        let new = texts(&["bug", "docs"]);
+        assert_eq!(missing(&new, &old), [false, true]);
+        assert_eq!(missing(&old, &new), [false, true, true]);
+    }
+}
@@ -0,0 +1,38 @@
+use egui::Pos2;
+use egui::Rangef;
+use egui::Rect;
+
+/// Turns `String` payloads dropped on the widget into chips, see
+/// [`ChipEditBuilder::drop_target`](crate::ChipEditBuilder::drop_target).
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub(crate) struct DropTarget {
+    /// Whether payloads can be dropped on the widget.
+    pub(crate) enabled: bool,
+}
+
+impl DropTarget {
+    /// Returns the index at which a payload dropped at `pointer` is inserted
+    /// among the chips laid out at `chip_rects`: before the first chip on the
+    /// pointer row right of the pointer, or before the first chip of a later
+    /// row.
+    pub fn index(chip_rects: &[Rect], pointer: Pos2) -> usize {
+        chip_rects
+            .iter()
+            .position(|rect| {
+                rect.top() > pointer.y || (rect.bottom() > pointer.y && rect.center().x > pointer.x)
+            })
+            .unwrap_or(chip_rects.len())
+    }
+
+    /// Returns where the marker of an insertion at `index` is drawn: the x
+    /// coordinate and the vertical span of the chip edge it sits on, or
+    /// `None` while there are no chips.
+    pub fn marker(chip_rects: &[Rect], index: usize) -> Option<(f32, Rangef)> {
+        match (chip_rects.get(index), chip_rects.last()) {
+            (Some(rect), _) => Some((rect.left(), rect.y_range())),
+            (None, Some(rect)) => Some((rect.right(), rect.y_range())),
+            (None, None) => None,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+/// Characters that end an address in
+/// [`ChipEditBuilder::email_mode`](crate::ChipEditBuilder::email_mode).
+pub(crate) const EMAIL_SEPARATORS: [char; 5] = [',', ';', ' ', '\n', '\t'];
+
+/// Splits typed or pasted text into addresses, dropping empty entries.
+pub(crate) fn split_addresses(text: &str) -> impl Iterator<Item = &str> {
+    text.split(EMAIL_SEPARATORS.as_slice())
+        .map(|address| address.trim().trim_start_matches('<').trim_end_matches('>'))
+        .filter(|address| !address.is_empty())
+}
+
+/// Returns whether `address` looks like `name@example.com`.
+///
+/// This is the check of a "To:" field, not RFC 5322: one `@` between a
+/// non-empty local part and a domain of non-empty dot-separated labels.
+pub(crate) fn is_email(address: &str) -> bool {
+    let Some((local, domain)) = address.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !address.chars().any(|c| c.is_whitespace() || c.is_control())
+        && domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addresses_are_checked() {
+        assert!(is_email("name@example.com"));
+        assert!(is_email("first.last+tag@mail.example-1.org"));
+        assert!(!is_email("name@localhost"));
+        assert!(!is_email("@example.com"));
+        assert!(!is_email("name@example..com"));
+        assert!(!is_email("na me@example.com"));
+        assert!(!is_email("name@exa@mple.com"));
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+use crate::ChipIcon;
+
+/// Errors returned when creating or configuring a chip editor.
+///
+/// More kinds of errors may be added, so matches need a wildcard arm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChipEditError {
+    /// The separator string was empty.
+    EmptySeparator,
+    /// The icon text was empty or longer than
+    /// [`ChipIcon::MAX_CHARS`](crate::ChipIcon::MAX_CHARS). Holds the number
+    /// of chars found.
+    InvalidIcon(usize),
+    /// The chip metadata could not be encoded or decoded. Holds the error
+    /// message. Only returned with the `typed-metadata` feature.
+    InvalidMetadata(String),
+}
+
+impl Display for ChipEditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptySeparator => write!(f, "separator cannot be empty"),
+            Self::InvalidIcon(found) => {
+                write!(
+                    f,
+                    "icon text needs 1 to {} chars but found {found}",
+                    ChipIcon::MAX_CHARS
+                )
+            }
+            Self::InvalidMetadata(message) => write!(f, "invalid chip metadata: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ChipEditError {}
@@ -0,0 +1,113 @@
+/// A change to the chips made by the user during one frame, see
+/// [`ChipEditOutput::events`](crate::ChipEditOutput::events).
+///
+/// Events are reported in the order they happened, and the indices of each
+/// are those of the chips right before it. Applying them in order to the
+/// chips of the last frame gives the chips of this frame, e.g. to update a
+/// database incrementally.
+///
+/// # Examples
+///
+/// ```
+/// use egui_chip::ChipEvent;
+///
+/// fn apply(rows: &mut Vec<String>, events: &[ChipEvent]) {
+///     for event in events {
+///         match event {
+///             ChipEvent::Added { index, text } => rows.insert(*index, text.clone()),
+///             ChipEvent::Removed { index, .. } => {
+///                 rows.remove(*index);
+///             }
+///             ChipEvent::Edited { index, new, .. } => rows[*index] = new.clone(),
+///             ChipEvent::Reordered { from, to } => {
+///                 let row = rows.remove(*from);
+///                 rows.insert(*to, row);
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChipEvent {
+    /// A chip was inserted at `index`.
+    Added {
+        /// The position of the new chip.
+        index: usize,
+        /// The text of the new chip.
+        text: String,
+    },
+    /// The chip at `index` was removed.
+    Removed {
+        /// The position the chip had.
+        index: usize,
+        /// The text the chip had.
+        text: String,
+    },
+    /// The text of the chip at `index` changed.
+    Edited {
+        /// The position of the chip.
+        index: usize,
+        /// The text before the change.
+        old: String,
+        /// The text after the change.
+        new: String,
+    },
+    /// The chip at `from` was taken out and inserted again at `to`.
+    Reordered {
+        /// The position the chip had.
+        from: usize,
+        /// The position the chip has now.
+        to: usize,
+    },
+}
+
+impl ChipEvent {
+    /// Returns the edit of the chip at `index` from `old` to `new`, or `None`
+    /// if the text did not change.
+    pub(crate) fn edit(index: usize, old: String, new: &str) -> Option<Self> {
+        (old != new).then(|| Self::Edited {
+            index,
+            old,
+            new: new.to_owned(),
+        })
+    }
+
+    /// Returns the moves that put the chips in `order`, where `order[to]` is
+    /// the position the chip now at `to` had.
+    pub(crate) fn moves(order: &[usize]) -> Vec<Self> {
+        let mut current: Vec<usize> = (0..order.len()).collect();
+        let mut moves = vec![];
+        for (to, chip) in order.iter().enumerate() {
+            let from = current
+                .iter()
+                .position(|c| c == chip)
+                .expect("order is a permutation");
+            if from != to {
+                let chip = current.remove(from);
+                current.insert(to, chip);
+                moves.push(Self::Reordered { from, to });
+            }
+        }
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moves_put_chips_in_order() {
+        let order = [2, 0, 3, 1];
+        let mut chips = vec![0, 1, 2, 3];
+        for event in ChipEvent::moves(&order) {
+            let ChipEvent::Reordered { from, to } = event else {
+                panic!("not a move: {event:?}");
+            };
+            let chip = chips.remove(from);
+            chips.insert(to, chip);
+        }
+        assert_eq!(chips, order);
+        assert!(ChipEvent::moves(&[0, 1, 2]).is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+use std::fmt::Display;
+
+/// A search filter held by a chip, e.g. `from:alice`, `label:rust` or the free
+/// text `"release notes"`. See
+/// [`ChipEditBuilder::search_filters`](crate::ChipEditBuilder::search_filters).
+///
+/// # Examples
+///
+/// ```
+/// use egui_chip::SearchFilter;
+///
+/// let filters = SearchFilter::parse_all(r#"from:alice "release notes" subject:"q3 plan""#);
+/// assert_eq!(
+///     filters,
+///     [
+///         SearchFilter::keyed("from", "alice"),
+///         SearchFilter::text("release notes"),
+///         SearchFilter::keyed("subject", "q3 plan"),
+///     ]
+/// );
+/// assert_eq!(filters[2].to_string(), r#"subject:"q3 plan""#);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SearchFilter {
+    /// The field filtered on, e.g. `from`, or `None` for free text.
+    pub key: Option<String>,
+    /// The value the field, or the free text, has to match.
+    pub value: String,
+}
+
+impl SearchFilter {
+    /// Creates a filter on `key` matching `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The field filtered on, e.g. `from`.
+    /// * `value` - The value the field has to match.
+    pub fn keyed(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            key: Some(key.into()),
+            value: value.into(),
+        }
+    }
+
+    /// Creates a free text filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The text to search for.
+    pub fn text(value: impl Into<String>) -> Self {
+        Self {
+            key: None,
+            value: value.into(),
+        }
+    }
+
+    /// Splits typed text into filters, the default parser of
+    /// [`ChipEditBuilder::search_filters`](crate::ChipEditBuilder::search_filters).
+    ///
+    /// Filters are separated by whitespace. A word made of letters, digits,
+    /// `_` or `-` followed by `:` is the key of the filter. Double quotes keep
+    /// whitespace and `:` in a value and are not part of it; a missing closing
+    /// quote is allowed so that a phrase can be typed.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The typed text.
+    pub fn parse_all(text: &str) -> Vec<Self> {
+        let mut filters = vec![];
+        let mut current = Self::default();
+        let mut quoted = false;
+        let mut in_quotes = false;
+        for c in text.chars() {
+            match c {
+                '"' => {
+                    in_quotes = !in_quotes;
+                    quoted = true;
+                }
+                ':' if !in_quotes
+                    && !quoted
+                    && current.key.is_none()
+                    && !current.value.is_empty()
+                    && current.value.chars().all(is_key_char) =>
+                {
+                    current.key = Some(std::mem::take(&mut current.value));
+                }
+                c if c.is_whitespace() && !in_quotes => {
+                    if quoted || current.key.is_some() || !current.value.is_empty() {
+                        filters.push(std::mem::take(&mut current));
+                    }
+                    quoted = false;
+                }
+                c => current.value.push(c),
+            }
+        }
+        if quoted || current.key.is_some() || !current.value.is_empty() {
+            filters.push(current);
+        }
+        filters
+    }
+
+    /// Reads the filter held by a chip, the inverse of [`Display`]. Text that
+    /// is not a single filter is free text.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text of the chip.
+    pub fn parse(text: &str) -> Self {
+        let mut filters = Self::parse_all(text);
+        if filters.len() == 1 {
+            filters.remove(0)
+        } else {
+            Self::text(text)
+        }
+    }
+}
+
+/// Returns whether `text` holds `separator`, only counting separators outside
+/// double quotes if `quotes` is set, like [`SearchFilter::parse_all`].
+pub(crate) fn holds_separator(text: &str, separator: &str, quotes: bool) -> bool {
+    if !quotes {
+        return text.contains(separator);
+    }
+    let mut in_quotes = false;
+    text.char_indices().any(|(at, c)| {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        !in_quotes && text[at..].starts_with(separator)
+    })
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+impl Display for SearchFilter {
+    /// Writes the filter as it is typed, quoting the value when needed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(key) = &self.key {
+            write!(f, "{key}:")?;
+        }
+        let needs_quotes = self.value.chars().any(char::is_whitespace)
+            || (self.key.is_none() && (self.value.is_empty() || self.value.contains(':')));
+        if needs_quotes {
+            write!(f, "\"{}\"", self.value)
+        } else {
+            write!(f, "{}", self.value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separators_between_quotes_are_kept() {
+        assert!(!holds_separator(r#"subject:"q3 plan"#, " ", true));
+        assert!(holds_separator(r#"subject:"q3 plan" "#, " ", true));
+        assert!(holds_separator(r#"subject:"q3 plan"#, " ", false));
+    }
+
+    #[test]
+    fn filters_are_parsed() {
+        assert_eq!(
+            SearchFilter::parse_all("  from:alice  rust "),
+            [
+                SearchFilter::keyed("from", "alice"),
+                SearchFilter::text("rust")
+            ]
+        );
+        assert_eq!(
+            SearchFilter::parse_all(r#""a:b" subject:"q3 plan"#),
+            [
+                SearchFilter::text("a:b"),
+                SearchFilter::keyed("subject", "q3 plan")
+            ]
+        );
+        assert_eq!(SearchFilter::parse_all(r#""""#), [SearchFilter::text("")]);
+        assert_eq!(
+            SearchFilter::parse_all("a.b:c"),
+            [SearchFilter::text("a.b:c")]
+        );
+        assert_eq!(
+            SearchFilter::parse("to: bob"),
+            SearchFilter::text("to: bob")
+        );
+    }
+}
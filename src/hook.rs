@@ -0,0 +1,84 @@
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use egui::Color32;
+use egui::ImageSource;
+use egui::Response;
+use egui::Ui;
+use egui::WidgetText;
+
+use crate::ChipIcon;
+use crate::CommittedChip;
+
+/// Renders a rich preview for the hovered chip at `index` with `text`.
+pub(crate) type HoverUiFn = dyn Fn(&mut Ui, usize, &str) + Send + Sync;
+
+/// Draws the content of the chip at `index` with `text`, given whether it
+/// has keyboard focus.
+pub(crate) type ChipUiFn = dyn Fn(&mut Ui, usize, &str, bool) -> Response + Send + Sync;
+
+/// Renders the right-click menu of the chip at `index` with `text`.
+pub(crate) type ContextMenuFn = dyn Fn(&mut Ui, usize, &str) + Send + Sync;
+
+/// Returns the tooltip of the chip with `text` at `index`, if any.
+pub(crate) type TooltipFn = dyn Fn(&str, usize) -> Option<WidgetText> + Send + Sync;
+
+/// Acts on the chip at `index` with `text`, e.g. by opening its page.
+pub(crate) type ActivateFn = dyn Fn(usize, &str) + Send + Sync;
+
+/// Returns `true` if the chip at `index` with `text` is locked.
+pub(crate) type LockedFn = dyn Fn(usize, &str) -> bool + Send + Sync;
+
+/// Turns committed `text` into the chips that replace it.
+pub(crate) type CommitFn = dyn Fn(&str) -> Vec<CommittedChip> + Send + Sync;
+
+/// Rewrites the text of a committed chip, e.g. to trim or lowercase it.
+pub(crate) type NormalizeFn = dyn Fn(&str) -> String + Send + Sync;
+
+/// Returns `true` if chips with the texts `a` and `b` are duplicates.
+pub(crate) type ChipEqFn = dyn Fn(&str, &str) -> bool + Send + Sync;
+
+/// Returns the background and text colors of the chip at `index` with `text`.
+pub(crate) type ChipColorFn = dyn Fn(&str, usize) -> (Color32, Color32) + Send + Sync;
+
+/// Returns the leading icon of the chip at `index` with `text`, if any.
+pub(crate) type IconFn = dyn Fn(&str, usize) -> Option<ChipIcon> + Send + Sync;
+
+/// Returns the leading image of the chip at `index` with `text`, if any.
+pub(crate) type ImageFn = dyn Fn(&str, usize) -> Option<ImageSource<'static>> + Send + Sync;
+
+/// Returns `true` if `char` may be typed or pasted into the chips.
+pub(crate) type CharFilterFn = dyn Fn(char) -> bool + Send + Sync;
+
+/// Returns `true` if the chip with `text` is invalid.
+pub(crate) type ChipValidateFn = dyn Fn(&str) -> bool + Send + Sync;
+
+/// Returns `true` if the chip values as a whole are invalid.
+pub(crate) type ValidateFn = dyn Fn(&[String]) -> bool + Send + Sync;
+
+/// A user callback stored on the widget.
+///
+/// Keeps the widget `Clone` and `Debug`. Callbacks are not serialized and
+/// need to be set again after deserializing.
+pub(crate) struct Hook<F: ?Sized>(pub(crate) Arc<F>);
+
+impl<F: ?Sized> Clone for Hook<F> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<F: ?Sized> Debug for Hook<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Hook(..)")
+    }
+}
+
+impl<F: ?Sized> Deref for Hook<F> {
+    type Target = F;
+
+    fn deref(&self) -> &F {
+        &self.0
+    }
+}
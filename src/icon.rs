@@ -0,0 +1,118 @@
+use egui::Color32;
+use egui::RichText;
+
+use crate::ChipEditError;
+
+/// The leading or trailing icon of a chip.
+///
+/// Unlike [`RichText`], it round-trips through serde, so that a persisted
+/// chip editor keeps its icon on reload.
+///
+/// # Examples
+///
+/// ```
+/// use egui_chip::ChipEditBuilder;
+/// use egui_chip::ChipIcon;
+///
+/// let chip_edit = ChipEditBuilder::new(",")
+///     .unwrap()
+///     .chip_icon(Some(ChipIcon::new("★").weak()))
+///     .unwrap()
+///     .build();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ChipIcon {
+    /// The text of the icon, usually a single char from an icon font, an emoji
+    /// or a short ligature. Icons wider than the chip allows are truncated.
+    pub text: String,
+    /// The font size, or the size of the body text if `None`.
+    pub size: Option<f32>,
+    /// Whether the icon is drawn with the strong text color.
+    pub strong: bool,
+    /// Whether the icon is drawn with the weak text color.
+    pub weak: bool,
+    /// The color of the icon, or the chip text color if `None`.
+    pub color: Option<Color32>,
+}
+
+impl ChipIcon {
+    /// The maximum number of chars in the text of an icon, enough for emoji
+    /// with modifiers and short ligatures.
+    pub const MAX_CHARS: usize = 8;
+
+    /// Creates an icon showing `text`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text of the icon.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the font size of the icon.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Draws the icon with the strong text color.
+    pub fn strong(mut self) -> Self {
+        self.strong = true;
+        self
+    }
+
+    /// Draws the icon with the weak text color.
+    pub fn weak(mut self) -> Self {
+        self.weak = true;
+        self
+    }
+
+    /// Sets the color of the icon.
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Checks that the text of `icon`, if any, is not empty and has at most
+    /// [`Self::MAX_CHARS`] chars.
+    pub(crate) fn validate(icon: Option<&Self>) -> Result<(), ChipEditError> {
+        match icon.map(|icon| icon.text.chars().count()) {
+            Some(found) if found == 0 || found > Self::MAX_CHARS => {
+                Err(ChipEditError::InvalidIcon(found))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Converts the icon to the [`RichText`] drawn in a chip, falling back to
+    /// `text_color` when no color is set.
+    pub(crate) fn rich_text(&self, text_color: Color32) -> RichText {
+        let mut text = RichText::new(&self.text).color(self.color.unwrap_or(text_color));
+        if let Some(size) = self.size {
+            text = text.size(size);
+        }
+        if self.strong {
+            text = text.strong();
+        }
+        if self.weak {
+            text = text.weak();
+        }
+        text
+    }
+}
+
+impl From<char> for ChipIcon {
+    fn from(icon: char) -> Self {
+        Self::new(icon)
+    }
+}
+
+impl From<&str> for ChipIcon {
+    fn from(icon: &str) -> Self {
+        Self::new(icon)
+    }
+}
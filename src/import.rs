@@ -0,0 +1,23 @@
+/// The "Import" link below the widget and the window in which chips are
+/// pasted in bulk, see
+/// [`ChipEditBuilder::import`](crate::ChipEditBuilder::import).
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub(crate) struct Import {
+    /// Whether the link is offered.
+    pub(crate) enabled: bool,
+    /// The text pasted in the window, while it is open.
+    #[serde(skip)]
+    pub(crate) text: Option<String>,
+}
+
+impl Import {
+    pub fn is_open(&self) -> bool {
+        self.text.is_some()
+    }
+
+    /// Opens the window with no text pasted yet.
+    pub fn open(&mut self) {
+        self.text = Some(String::new());
+    }
+}
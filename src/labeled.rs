@@ -0,0 +1,92 @@
+use egui::Align;
+use egui::Layout;
+use egui::Response;
+use egui::Ui;
+use egui::Widget;
+use egui::WidgetText;
+use egui::vec2;
+
+use crate::ChipEdit;
+use crate::ChipEditOutput;
+
+/// A [`ChipEdit`] preceded by a label, created with [`ChipEdit::labeled`].
+///
+/// The label is centered on the first row of chips. The label and the field
+/// share a row, or take one cell each with [`Self::in_grid`].
+///
+/// # Examples
+///
+/// ```
+/// use egui_chip::ChipEditBuilder;
+///
+/// # egui::__run_test_ui(|ui| {
+/// let mut chip_edit = ChipEditBuilder::new(",")
+///     .unwrap()
+///     .texts(["rust", "egui"])
+///     .build();
+/// egui::Grid::new("settings").num_columns(2).show(ui, |ui| {
+///     ui.add(chip_edit.labeled("Tags:").in_grid(true));
+///     ui.end_row();
+/// });
+/// # });
+/// ```
+pub struct LabeledChipEdit<'a> {
+    chip_edit: &'a mut ChipEdit,
+    label: WidgetText,
+    in_grid: bool,
+}
+
+impl<'a> LabeledChipEdit<'a> {
+    pub(crate) fn new(chip_edit: &'a mut ChipEdit, label: impl Into<WidgetText>) -> Self {
+        Self {
+            chip_edit,
+            label: label.into(),
+            in_grid: false,
+        }
+    }
+
+    /// Puts the label and the field in a cell each, for use inside an
+    /// [`egui::Grid`], instead of on a shared row. egui does not tell
+    /// widgets whether they are in a grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_grid` - Whether the label and the field are grid cells.
+    pub fn in_grid(mut self, in_grid: bool) -> Self {
+        self.in_grid = in_grid;
+        self
+    }
+
+    /// Shows the label and the field.
+    ///
+    /// # Returns
+    ///
+    /// The output of the field, whose response is labelled by the label.
+    pub fn show(self, ui: &mut Ui) -> ChipEditOutput {
+        if self.in_grid {
+            self.show_inline(ui)
+        } else {
+            ui.horizontal_top(|ui| self.show_inline(ui)).inner
+        }
+    }
+
+    fn show_inline(self, ui: &mut Ui) -> ChipEditOutput {
+        let height = self.chip_edit.unowned.row_height(ui) - ui.spacing().item_spacing.y;
+        let label = ui
+            .allocate_ui_with_layout(
+                vec2(ui.available_width(), height),
+                Layout::left_to_right(Align::Center),
+                |ui| ui.label(self.label),
+            )
+            .inner;
+        let mut ret = self.chip_edit.show(ui);
+        ret.response = ret.response.labelled_by(label.id);
+        ret
+    }
+}
+
+impl Widget for LabeledChipEdit<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
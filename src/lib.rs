@@ -31,17 +31,68 @@
 //! - Customizable options for appearance
 //! - Supports moving from one chip to another
 //! - Supports deleting chip with delete or backspace keys
+//! - Stateless [`chips`] function for immediate-mode code
 //! - Integration with the `egui` framework
+//!
+//! ## Optional features
+//!
+//! - `diacritics`: match suggestions regardless of diacritics, so that typing
+//!   "uber" suggests "Über"
+//! - `typed-metadata`: attach any `Serialize + DeserializeOwned` type as chip
+//!   metadata, so that persisting the widget keeps the app data of every chip
 #![warn(clippy::all)]
 
+mod blur;
 mod builder;
 mod chip;
 mod chip_edit;
+mod chips;
+mod color;
+mod commit;
+mod date;
+mod diff;
+mod drop;
+mod email;
+mod error;
+mod event;
+mod filter;
+mod hook;
+mod icon;
+mod import;
+mod labeled;
+mod mention;
 mod output;
+mod renderer;
+mod selection;
+mod sort;
 mod state;
+mod style;
+mod suggestions;
+mod summary;
+mod typed;
 mod unowned_chip_edit;
 
+pub use blur::BlurPolicy;
 pub use builder::ChipEditBuilder;
 pub use chip_edit::ChipEdit;
+pub use chips::chips;
+pub use commit::CommittedChip;
+pub use diff::ChipDiff;
+pub use error::ChipEditError;
+pub use event::ChipEvent;
+pub use filter::SearchFilter;
+pub use icon::ChipIcon;
+pub use labeled::LabeledChipEdit;
+pub use mention::Mention;
 pub use output::ChipEditOutput;
+pub use renderer::ChipRenderer;
+pub use renderer::ChipView;
+pub use renderer::DefaultChipRenderer;
+pub use sort::ChipSort;
+pub use style::ChipEditStyle;
+pub use style::ChipShape;
+pub use style::ChipStyle;
+pub use style::ChipVariant;
+pub use summary::ChipSummary;
+pub use typed::TypedChipEdit;
 pub use unowned_chip_edit::UnownedChipEdit;
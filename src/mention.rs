@@ -0,0 +1,81 @@
+use crate::suggestions::MAX_VISIBLE;
+use crate::suggestions::Suggestions;
+use crate::suggestions::fold;
+
+/// A person that can be mentioned, see
+/// [`ChipEditBuilder::mentions`](crate::ChipEditBuilder::mentions).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub struct Mention {
+    /// The id of the person in the app, e.g. a user id.
+    pub id: String,
+    /// The name shown in the suggestions and in the chip.
+    pub name: String,
+}
+
+impl Mention {
+    /// Creates a person that can be mentioned.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the person in the app.
+    /// * `name` - The name shown in the suggestions and in the chip.
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+        }
+    }
+}
+
+/// The people offered in a popup while typing a mention, e.g. `@al`.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub(crate) struct Mentions {
+    /// The character that starts a mention.
+    pub(crate) trigger: char,
+    /// All people that can be mentioned.
+    pub(crate) people: Vec<Mention>,
+    /// The state of the popup.
+    pub(crate) popup: Suggestions,
+}
+
+impl Default for Mentions {
+    fn default() -> Self {
+        Self {
+            trigger: '@',
+            people: vec![],
+            popup: Suggestions::new(),
+        }
+    }
+}
+
+impl Mentions {
+    pub fn is_empty(&self) -> bool {
+        self.people.is_empty()
+    }
+
+    /// Returns the text typed after the trigger if `text` is a mention.
+    pub fn query<'a>(&self, text: &'a str) -> Option<&'a str> {
+        text.strip_prefix(self.trigger)
+    }
+
+    /// Returns the indices of the people whose name or id contains `query`
+    /// regardless of case.
+    pub fn matches(&self, query: &str) -> Vec<usize> {
+        let query = fold(query);
+        self.people
+            .iter()
+            .enumerate()
+            .filter(|(_, person)| {
+                fold(&person.name).contains(&query) || fold(&person.id).contains(&query)
+            })
+            .map(|(index, _)| index)
+            .take(MAX_VISIBLE)
+            .collect()
+    }
+
+    /// Returns the text of the chip mentioning `person`.
+    pub fn text(&self, person: &Mention) -> String {
+        format!("{}{}", self.trigger, person.name)
+    }
+}
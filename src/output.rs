@@ -3,12 +3,15 @@ use egui::Response;
 use egui::text::CursorRange;
 use egui::text_edit::TextEditOutput;
 
+use crate::ChipEvent;
+
 /// Represents the output of a `ChipEdit` widget.
 pub struct ChipEditOutput {
     /// The response from the widget.
     /// As a single ChipEdit can have multiple TextEdits in it,
     /// the `Response` is the `Response::union` of all the containing
-    /// responses.
+    /// responses. It is marked changed whenever the chips or any text
+    /// changed this frame, including chips added, removed or merged.
     pub response: Response,
 
     /// The range of the cursor within the text.
@@ -16,6 +19,71 @@ pub struct ChipEditOutput {
 
     /// True if the widget gained focus.
     pub gained_focus: bool,
+
+    /// True if at least one chip was wider than the row and had to be shrunk
+    /// (and truncated) to fit.
+    pub overflow: bool,
+
+    /// The position of the focused chip in the texts, if a chip (rather than
+    /// the gap between chips) has keyboard focus.
+    pub focused_index: Option<usize>,
+
+    /// True if chips were split, merged, deleted, inserted or reordered this
+    /// frame, or by the app since the last frame, e.g. with
+    /// [`UnownedChipEdit::push`](crate::UnownedChipEdit::push), which
+    /// invalidates chip indices cached from earlier frames.
+    pub structure_changed: bool,
+
+    /// The position of the chip whose leading icon or image was clicked.
+    pub leading_icon_clicked: Option<usize>,
+
+    /// The position of the chip whose trailing icon was clicked, see
+    /// [`ChipEditBuilder::chip_trailing_icon`](crate::ChipEditBuilder::chip_trailing_icon).
+    pub trailing_icon_clicked: Option<usize>,
+
+    /// The position the chip removed with the pointer button set with
+    /// [`ChipEditBuilder::delete_button`](crate::ChipEditBuilder::delete_button)
+    /// had, if one was removed this frame.
+    pub pointer_deleted: Option<usize>,
+
+    /// The texts of the selected chips removed at once with Delete or
+    /// Backspace this frame, in order, see
+    /// [`ChipEditBuilder::multi_select`](crate::ChipEditBuilder::multi_select).
+    pub deleted: Vec<String>,
+
+    /// The changes the user made to the chips this frame, in order, so that
+    /// they can be applied elsewhere without comparing all values.
+    pub events: Vec<ChipEvent>,
+
+    /// The response of every chip shown this frame, by chip position, e.g. to
+    /// attach tooltips, context menus or drag payloads to chips. The
+    /// positions are those the chips had while they were shown: after the
+    /// edits made before the chips are drawn, e.g. by quick delete, moves,
+    /// removing the selection or the commit keys, and before the edits made
+    /// from what was typed into them, e.g. splits and merges.
+    pub chip_responses: Vec<Response>,
+
+    /// The positions of the chips selected with Ctrl+click or Shift+click, in
+    /// order, see
+    /// [`ChipEditBuilder::multi_select`](crate::ChipEditBuilder::multi_select).
+    pub selected: Vec<usize>,
+
+    /// True if the chips in [`Self::selected`] changed this frame.
+    pub selection_changed: bool,
+
+    /// True if the widget is drawn as invalid, see
+    /// [`ChipEditBuilder::invalid_if`](crate::ChipEditBuilder::invalid_if).
+    pub invalid: bool,
+
+    /// Whether the cursor was moved back past the start of the first unit.
+    pub(crate) wants_previous_widget: bool,
+
+    /// Whether the cursor was moved on past the end of the last unit.
+    pub(crate) wants_next_widget: bool,
+
+    /// Char offset of the editor reporting `cursor_range` within the chip
+    /// text, for chips made of more than one editor.
+    pub(crate) cursor_offset: usize,
 }
 
 impl ChipEditOutput {
@@ -29,12 +97,95 @@ impl ChipEditOutput {
             response,
             cursor_range,
             gained_focus,
+            overflow,
+            focused_index,
+            structure_changed,
+            leading_icon_clicked,
+            trailing_icon_clicked,
+            pointer_deleted,
+            deleted,
+            events,
+            chip_responses,
+            invalid,
+            wants_previous_widget,
+            wants_next_widget,
+            selected,
+            selection_changed,
+            cursor_offset,
         } = other;
         self.gained_focus |= gained_focus || response.gained_focus();
+        self.overflow |= overflow;
+        self.structure_changed |= structure_changed;
+        self.invalid |= invalid;
+        self.wants_previous_widget |= wants_previous_widget;
+        self.wants_next_widget |= wants_next_widget;
         self.response = self.response.union(response);
         if self.cursor_range.is_none() {
             self.cursor_range = cursor_range;
+            self.cursor_offset = cursor_offset;
         }
+        self.focused_index = self.focused_index.or(focused_index);
+        self.leading_icon_clicked = self.leading_icon_clicked.or(leading_icon_clicked);
+        self.trailing_icon_clicked = self.trailing_icon_clicked.or(trailing_icon_clicked);
+        self.pointer_deleted = self.pointer_deleted.or(pointer_deleted);
+        self.deleted.extend(deleted);
+        self.events.extend(events);
+        self.chip_responses.extend(chip_responses);
+        if self.selected.is_empty() {
+            self.selected = selected;
+        }
+        self.selection_changed |= selection_changed;
+    }
+
+    /// Returns the response of the chip at `index` as it was shown this frame,
+    /// see [`Self::chip_responses`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip.
+    pub fn chip_response(&self, index: usize) -> Option<&Response> {
+        self.chip_responses.get(index)
+    }
+
+    /// Returns whether the chips or any text, including text typed between
+    /// chips, changed this frame.
+    pub fn any_changed(&self) -> bool {
+        self.response.changed() || self.structure_changed || !self.events.is_empty()
+    }
+
+    /// Returns the position of the chip whose leading or trailing icon was
+    /// clicked, if any.
+    ///
+    /// With [`ChipEditBuilder::delete_on_icon_click`](crate::ChipEditBuilder::delete_on_icon_click)
+    /// the chip is deleted by then, and the position is the one it had.
+    pub fn icon_clicked(&self) -> Option<usize> {
+        self.leading_icon_clicked.or(self.trailing_icon_clicked)
+    }
+
+    /// Returns whether the user pressed the left arrow key at the start of the
+    /// first chip or gap, so that the app can move the focus to the widget
+    /// before this one.
+    pub fn wants_previous_widget(&self) -> bool {
+        self.wants_previous_widget
+    }
+
+    /// Returns whether the user pressed the right arrow key at the end of the
+    /// trailing gap, so that the app can move the focus to the widget after
+    /// this one.
+    pub fn wants_next_widget(&self) -> bool {
+        self.wants_next_widget
+    }
+
+    /// Returns the character position of the text cursor in the focused
+    /// editor.
+    ///
+    /// # Returns
+    ///
+    /// The char index of the cursor, or `None` if no editor has focus.
+    pub fn cursor_position(&self) -> Option<usize> {
+        self.cursor_range
+            .as_ref()
+            .map(|cursor| cursor.primary.ccursor.index + self.cursor_offset)
     }
 
     /// Checks if the cursor is at the specified position.
@@ -48,7 +199,8 @@ impl ChipEditOutput {
     /// `true` if the cursor is at the specified position, `false` otherwise.
     pub fn cursor_at(&self, pos: usize) -> bool {
         if let Some(cursor) = &self.cursor_range {
-            cursor.single().is_some() && (cursor.as_sorted_char_range().end == pos)
+            cursor.single().is_some()
+                && (cursor.as_sorted_char_range().end + self.cursor_offset == pos)
         } else {
             false
         }
@@ -114,6 +266,21 @@ impl From<TextEditOutput> for ChipEditOutput {
             gained_focus: value.response.gained_focus(),
             response: value.response,
             cursor_range: value.cursor_range,
+            overflow: false,
+            focused_index: None,
+            structure_changed: false,
+            leading_icon_clicked: None,
+            trailing_icon_clicked: None,
+            pointer_deleted: None,
+            invalid: false,
+            wants_previous_widget: false,
+            wants_next_widget: false,
+            selected: vec![],
+            selection_changed: false,
+            deleted: vec![],
+            events: vec![],
+            chip_responses: vec![],
+            cursor_offset: 0,
         }
     }
 }
@@ -124,6 +291,21 @@ impl From<Response> for ChipEditOutput {
             gained_focus: response.gained_focus(),
             response,
             cursor_range: None,
+            overflow: false,
+            focused_index: None,
+            structure_changed: false,
+            leading_icon_clicked: None,
+            trailing_icon_clicked: None,
+            pointer_deleted: None,
+            invalid: false,
+            wants_previous_widget: false,
+            wants_next_widget: false,
+            selected: vec![],
+            selection_changed: false,
+            deleted: vec![],
+            events: vec![],
+            chip_responses: vec![],
+            cursor_offset: 0,
         }
     }
 }
@@ -0,0 +1,165 @@
+use egui::Id;
+use egui::Ui;
+use egui::Vec2;
+
+use crate::ChipEditOutput;
+use crate::ChipStyle;
+use crate::chip::Chip;
+
+/// Draws the chips and the gaps between them.
+///
+/// The widget keeps laying out the units, moving the focus and splitting,
+/// merging and deleting chips; a renderer only decides what a unit looks
+/// like. Every method defaults to the built-in look, so a skin overrides only
+/// what it changes and can fall back to [`ChipView::draw`] for the rest.
+///
+/// # Examples
+///
+/// ```
+/// use egui::Id;
+/// use egui::Label;
+/// use egui::RichText;
+/// use egui::Sense;
+/// use egui::Ui;
+/// use egui::Vec2;
+/// use egui_chip::ChipEditBuilder;
+/// use egui_chip::ChipEditOutput;
+/// use egui_chip::ChipRenderer;
+/// use egui_chip::ChipView;
+///
+/// /// Shouts every chip that is not being edited.
+/// struct Loud;
+///
+/// impl ChipRenderer for Loud {
+///     fn draw_chip(
+///         &self,
+///         ui: &mut Ui,
+///         chip: &mut ChipView<'_>,
+///         id: Option<Id>,
+///         focused: bool,
+///         size: Option<Vec2>,
+///         text: &mut String,
+///     ) -> ChipEditOutput {
+///         if focused {
+///             chip.draw(ui, id, focused, size, text)
+///         } else {
+///             let text = RichText::new(text.to_uppercase()).strong();
+///             ui.add(Label::new(text).sense(Sense::click())).into()
+///         }
+///     }
+/// }
+///
+/// let chip_edit = ChipEditBuilder::new(",").unwrap().renderer(Loud).build();
+/// ```
+pub trait ChipRenderer: Send + Sync {
+    /// Draws a chip showing `text`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ui` - The ui to draw the chip in.
+    /// * `chip` - The chip, for its styling and the built-in look.
+    /// * `id` - The id of the chip editor, if the widget has an id salt.
+    /// * `focused` - Whether the chip is edited.
+    /// * `size` - The content size from [`Self::preferred_size`].
+    /// * `text` - The text of the chip, edited in place.
+    ///
+    /// # Returns
+    ///
+    /// The output of the chip, whose response takes the keyboard focus.
+    fn draw_chip(
+        &self,
+        ui: &mut Ui,
+        chip: &mut ChipView<'_>,
+        id: Option<Id>,
+        focused: bool,
+        size: Option<Vec2>,
+        text: &mut String,
+    ) -> ChipEditOutput {
+        chip.draw(ui, id, focused, size, text)
+    }
+
+    /// Draws the gap between two chips, in which new chips are typed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ui` - The ui to draw the gap in.
+    /// * `gap` - The gap, for the built-in look.
+    /// * `id` - The id of the gap editor, if the widget has an id salt.
+    /// * `text` - The text typed in the gap, edited in place.
+    fn draw_separator(
+        &self,
+        ui: &mut Ui,
+        gap: &mut ChipView<'_>,
+        id: Option<Id>,
+        text: &mut String,
+    ) -> ChipEditOutput {
+        gap.draw_separator(ui, id, text)
+    }
+
+    /// Returns the size of the content of a chip showing `text`, or `None` to
+    /// fit the text. Defaults to [`ChipStyle::size`] snapped to
+    /// physical pixels.
+    fn preferred_size(&self, ui: &Ui, chip: &ChipView<'_>, text: &str) -> Option<Vec2> {
+        let _ = text;
+        chip.preferred_size(ui)
+    }
+}
+
+/// The built-in look of the chips.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultChipRenderer;
+
+impl ChipRenderer for DefaultChipRenderer {}
+
+/// A chip or gap handed to a [`ChipRenderer`].
+pub struct ChipView<'a>(pub(crate) &'a mut Chip);
+
+impl ChipView<'_> {
+    /// Returns the styling of the chip.
+    pub fn style(&self) -> &ChipStyle {
+        &self.0.style
+    }
+
+    /// Returns the label shown instead of the text, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.0.label.as_deref()
+    }
+
+    /// Returns whether the chip is read-only this frame, because it is locked
+    /// with [`UnownedChipEdit::lock`](crate::UnownedChipEdit::lock) or by the
+    /// app's predicate.
+    pub fn is_read_only(&self) -> bool {
+        self.0.read_only
+    }
+
+    /// Returns the built-in size of the chip content, see
+    /// [`ChipRenderer::preferred_size`].
+    pub fn preferred_size(&self, ui: &Ui) -> Option<Vec2> {
+        self.0.preferred_size(ui).map(Vec2::from)
+    }
+
+    /// Draws the chip with the built-in look, see
+    /// [`ChipRenderer::draw_chip`].
+    pub fn draw(
+        &mut self,
+        ui: &mut Ui,
+        id: Option<Id>,
+        focused: bool,
+        size: Option<Vec2>,
+        text: &mut String,
+    ) -> ChipEditOutput {
+        self.0
+            .show_chip(ui, id, focused, size.map(|size| [size.x, size.y]), text)
+    }
+
+    /// Draws the gap with the built-in look, see
+    /// [`ChipRenderer::draw_separator`].
+    pub fn draw_separator(
+        &mut self,
+        ui: &mut Ui,
+        id: Option<Id>,
+        text: &mut String,
+    ) -> ChipEditOutput {
+        self.0.show_separator(ui, id, text)
+    }
+}
@@ -0,0 +1,24 @@
+use egui::Ui;
+
+/// The chips picked with Ctrl+click or Shift+click, see
+/// [`ChipEditBuilder::multi_select`](crate::ChipEditBuilder::multi_select).
+///
+/// The chips themselves remember whether they are selected.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub(crate) struct Selection {
+    /// Whether chips can be selected.
+    pub(crate) enabled: bool,
+    /// Whether the whole text of the focused chip was selected at the end of
+    /// the last `show`, so that Ctrl+A selects all chips.
+    #[serde(skip)]
+    pub(crate) chip_text_selected: bool,
+}
+
+impl Selection {
+    /// Returns whether a click on a chip this frame selects it rather than
+    /// focusing it.
+    pub fn click_selects(&self, ui: &Ui) -> bool {
+        self.enabled && ui.input(|i| i.modifiers.command || i.modifiers.shift)
+    }
+}
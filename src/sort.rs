@@ -0,0 +1,43 @@
+use std::cmp::Ordering;
+
+use serde::Serialize;
+use serde::Serializer;
+
+/// The order chips are kept in, see
+/// [`ChipEditBuilder::sort`](crate::ChipEditBuilder::sort).
+///
+/// A saved widget keeps the built-in orders. A [`Self::Custom`] order cannot
+/// be saved and is restored as no order, to be set again by the app.
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
+pub enum ChipSort {
+    /// Alphabetical regardless of case.
+    Alphabetical,
+    /// Shortest first, chips of equal length keep their order.
+    ByLength,
+    /// Ordered by a comparison of the chip texts.
+    #[serde(skip)]
+    Custom(fn(&str, &str) -> Ordering),
+}
+
+impl ChipSort {
+    /// Compares the texts of two chips.
+    pub(crate) fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            Self::Alphabetical => a
+                .to_lowercase()
+                .cmp(&b.to_lowercase())
+                .then_with(|| a.cmp(b)),
+            Self::ByLength => a.chars().count().cmp(&b.chars().count()),
+            Self::Custom(compare) => compare(a, b),
+        }
+    }
+}
+
+/// Saves `sort`, with a custom order saved as no order.
+pub(crate) fn save_sort<S: Serializer>(
+    sort: &Option<ChipSort>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    sort.filter(|sort| !matches!(sort, ChipSort::Custom(_)))
+        .serialize(serializer)
+}
@@ -2,6 +2,7 @@ use egui::Key;
 
 use crate::ChipEditOutput;
 use crate::chip::Chip;
+use crate::filter::holds_separator;
 
 #[derive(Debug)]
 pub(crate) struct State {
@@ -19,6 +20,37 @@ pub(crate) struct State {
 
     // chip at index needs to be deleted
     pub delete: Option<usize>,
+
+    // the cursor was moved back past the first unit or on past the last one
+    pub leave_backward: bool,
+    pub leave_forward: bool,
+
+    // typed text filters suggestions instead of becoming chips, and chips
+    // are deleted instead of merged
+    pub fixed_vocabulary: bool,
+
+    // typed text stays in the gaps as free text instead of becoming chips
+    pub free_text: bool,
+
+    // separators typed between double quotes do not split chips
+    pub quotes: bool,
+
+    // clicks on chips change the multi-selection instead of the focus
+    pub select_click: bool,
+
+    // Backspace at the start of a gap highlights the chip before it, and
+    // deletes it only once it is highlighted
+    pub two_stage_backspace: bool,
+
+    // the chip highlighted by the last Backspace, and the one highlighted
+    // this iteration
+    pub armed: Option<usize>,
+    pub arm: Option<usize>,
+
+    // the text the chip at index had when it gained focus, and the chip
+    // whose edit Escape cancelled
+    pub snapshot: Option<(usize, String)>,
+    pub revert: Option<usize>,
 }
 
 impl From<&Option<usize>> for State {
@@ -37,6 +69,17 @@ impl State {
             merge: None,
             split: None,
             delete: None,
+            leave_backward: false,
+            leave_forward: false,
+            fixed_vocabulary: false,
+            free_text: false,
+            quotes: false,
+            select_click: false,
+            two_stage_backspace: false,
+            armed: None,
+            arm: None,
+            snapshot: None,
+            revert: None,
         }
     }
 
@@ -65,16 +108,38 @@ impl State {
     ) {
         let resp = &output.response;
 
-        if resp.changed() && unit.is_separator() && !text.is_empty() {
+        let gap_text = self.fixed_vocabulary || self.free_text;
+        if !gap_text && resp.changed() && unit.is_separator() && !text.is_empty() {
             self.split = Some(index);
             self.set_focus(index + 1);
         }
 
-        if self.split.is_none() && output.response.changed() && text.contains(separator) {
+        if !self.fixed_vocabulary
+            && (!self.free_text || !unit.is_separator())
+            && self.split.is_none()
+            && output.response.changed()
+            && holds_separator(text, separator, self.quotes)
+        {
             self.split = Some(index);
             self.set_focus(index);
         }
 
+        let snapshot_of = |at: usize| self.snapshot.as_ref().is_some_and(|(s, _)| *s == at);
+        if !unit.is_separator() {
+            if output.gained_focus() && !snapshot_of(index) {
+                self.snapshot = Some((index, text.to_owned()));
+            } else if output.lost_focus()
+                && output.is_key_pressed(Key::Escape)
+                && snapshot_of(index)
+            {
+                self.revert = Some(index);
+            }
+        }
+
+        if self.select_click && !unit.is_separator() && resp.clicked() {
+            return;
+        }
+
         if output.gained_focus() {
             self.set_focus(index);
         } else if output.lost_focus() && self.focus == Some(index) {
@@ -86,7 +151,25 @@ impl State {
         let act_at_end = unit.at_end() && output.cursor_at_end(text);
         let act_at_start = unit.at_start() && output.cursor_at_start();
 
-        if resp.has_focus() {
+        if resp.has_focus()
+            && !unit.is_separator()
+            && (self.fixed_vocabulary
+                || unit.read_only
+                || unit.content_ui.is_some()
+                || unit.date_picker
+                || unit.swatch.is_some())
+        {
+            let delete =
+                output.is_key_pressed(Key::Backspace) || output.is_key_pressed(Key::Delete);
+            if delete && !unit.read_only {
+                self.set_focus(index - 1);
+                self.delete = Some(index);
+            } else if output.is_key_pressed(Key::ArrowRight) {
+                self.set_focus(index + 1);
+            } else if output.is_key_pressed(Key::ArrowLeft) {
+                self.set_focus(index - 1);
+            }
+        } else if resp.has_focus() {
             if output.is_key_pressed(Key::Delete) && act_at_end && index < max_index {
                 self.set_focus(index);
                 if unit.is_separator() {
@@ -94,6 +177,14 @@ impl State {
                 } else {
                     self.set_merge(index, index + 2);
                 }
+            } else if output.is_key_pressed(Key::Backspace)
+                && act_at_start
+                && index > 1
+                && unit.is_separator()
+                && self.two_stage_backspace
+                && self.armed != Some(index - 1)
+            {
+                self.arm = Some(index - 1);
             } else if output.is_key_pressed(Key::Backspace) && act_at_start && index > 1 {
                 self.set_focus(index - 2);
                 if unit.is_separator() {
@@ -105,6 +196,10 @@ impl State {
                 self.set_focus(index + 1);
             } else if output.is_key_pressed(Key::ArrowLeft) && act_at_start && index > 0 {
                 self.set_focus(index - 1);
+            } else if output.is_key_pressed(Key::ArrowRight) && act_at_end {
+                self.leave_forward = true;
+            } else if output.is_key_pressed(Key::ArrowLeft) && act_at_start {
+                self.leave_backward = true;
             }
         }
     }
@@ -0,0 +1,436 @@
+use egui::Color32;
+use egui::CornerRadius;
+use egui::Margin;
+use egui::Mesh;
+use egui::Rect;
+use egui::Shadow;
+use egui::Shape;
+use egui::Stroke;
+use egui::StrokeKind;
+use egui::TextStyle;
+use egui::Vec2;
+use egui::epaint::RectShape;
+use egui::pos2;
+use egui::vec2;
+
+use crate::ChipIcon;
+use crate::chip::DEFAULT_CHIP_SIZE;
+
+/// The silhouette of a chip.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum ChipShape {
+    /// A rectangle with rounded corners.
+    #[default]
+    Rounded,
+    /// A rectangle whose short sides are half circles.
+    Pill,
+    /// A rectangle with sharp corners.
+    Square,
+    /// A parallelogram leaning to the right.
+    Slanted,
+}
+
+impl ChipShape {
+    /// Returns how far the top edge of a [`ChipShape::Slanted`] chip is
+    /// shifted relative to its bottom edge.
+    pub(crate) fn slant(&self, height: f32) -> f32 {
+        match self {
+            Self::Slanted => height / 4.,
+            _ => 0.,
+        }
+    }
+
+    /// Returns the corner radius of a chip covering `rect`, approximating
+    /// slanted chips with sharp corners.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The rect of the chip.
+    /// * `corner_radius` - The radius of [`ChipShape::Rounded`] chips.
+    pub(crate) fn corner_radius(&self, rect: Rect, corner_radius: CornerRadius) -> CornerRadius {
+        match self {
+            Self::Rounded => corner_radius,
+            Self::Pill => CornerRadius::same((rect.height() / 2.).min(u8::MAX as f32) as u8),
+            Self::Square | Self::Slanted => CornerRadius::ZERO,
+        }
+    }
+
+    /// Returns the shape filling `rect` with `fill` and outlining it with
+    /// `stroke`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The rect of the chip.
+    /// * `corner_radius` - The radius of [`ChipShape::Rounded`] chips.
+    /// * `fill` - The background color of the chip.
+    /// * `stroke` - The outline of the chip, drawn inside `rect`.
+    pub(crate) fn paint(
+        &self,
+        rect: Rect,
+        corner_radius: CornerRadius,
+        fill: Color32,
+        stroke: Stroke,
+    ) -> Shape {
+        match self {
+            Self::Rounded | Self::Pill | Self::Square => RectShape::new(
+                rect,
+                self.corner_radius(rect, corner_radius),
+                fill,
+                stroke,
+                StrokeKind::Inside,
+            )
+            .into(),
+            Self::Slanted => {
+                let slant = self.slant(rect.height());
+                let points = vec![
+                    pos2(rect.left() + slant, rect.top()),
+                    pos2(rect.right(), rect.top()),
+                    pos2(rect.right() - slant, rect.bottom()),
+                    pos2(rect.left(), rect.bottom()),
+                ];
+                Shape::convex_polygon(points, fill, stroke)
+            }
+        }
+    }
+}
+
+/// The emphasis of a chip, after the Material 3 chip variants.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum ChipVariant {
+    /// A solid background in the chip background color.
+    #[default]
+    Filled,
+    /// A transparent background with an outline and text in the chip
+    /// background color, for lower-emphasis contexts.
+    Outlined,
+    /// A solid background lifted by a drop shadow, used unless
+    /// [`ChipStyle::shadow`] is set.
+    Elevated,
+}
+
+impl ChipVariant {
+    /// The shadow of [`ChipVariant::Elevated`] chips.
+    pub(crate) const ELEVATION: Shadow = Shadow {
+        offset: [0, 1],
+        blur: 3,
+        spread: 0,
+        color: Color32::from_black_alpha(60),
+    };
+}
+
+/// Returns a gradient over `rect` that fades from `color` at the clipped edge
+/// to transparent, hinting that more chips are scrolled out of view.
+///
+/// # Arguments
+///
+/// * `rect` - The area of the fade.
+/// * `color` - The widget background color.
+/// * `left` - Whether the clipped edge is the left one.
+pub(crate) fn edge_fade(rect: Rect, color: Color32, left: bool) -> Shape {
+    let (left_color, right_color) = if left {
+        (color, Color32::TRANSPARENT)
+    } else {
+        (Color32::TRANSPARENT, color)
+    };
+    let mut mesh = Mesh::default();
+    mesh.colored_vertex(rect.left_top(), left_color);
+    mesh.colored_vertex(rect.right_top(), right_color);
+    mesh.colored_vertex(rect.left_bottom(), left_color);
+    mesh.colored_vertex(rect.right_bottom(), right_color);
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(2, 1, 3);
+    mesh.into()
+}
+
+/// The look of the chips of a chip editor.
+///
+/// Can be shared by many widgets with [`ChipEditBuilder::style`] and
+/// round-trips through serde, e.g. to keep a theme in the app settings.
+///
+/// [`ChipEditBuilder::style`]: crate::ChipEditBuilder::style
+///
+/// # Examples
+///
+/// ```
+/// use egui::Color32;
+/// use egui_chip::ChipEditBuilder;
+/// use egui_chip::ChipShape;
+/// use egui_chip::ChipStyle;
+///
+/// let style = ChipStyle::default()
+///     .colors(Color32::DARK_GREEN, Color32::WHITE)
+///     .shape(ChipShape::Pill);
+/// let tags = ChipEditBuilder::new(",").unwrap().style(style.clone()).build();
+/// let labels = ChipEditBuilder::new(",").unwrap().style(style).build();
+/// ```
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ChipStyle {
+    /// The background color of the chips.
+    pub bg_color: Option<Color32>,
+    /// The text color of the chips.
+    pub text_color: Option<Color32>,
+    /// The outline of the chips, drawn inside their background.
+    pub stroke: Option<Stroke>,
+    /// The background color of hovered chips. Defaults to a lighter tint of
+    /// the background color.
+    pub hover_bg_color: Option<Color32>,
+    /// The background color of pressed chips. Defaults to a darker shade of
+    /// the background color.
+    pub pressed_bg_color: Option<Color32>,
+    /// The outline of the chip being edited. Defaults to the selection
+    /// stroke of the ui.
+    pub focus_stroke: Option<Stroke>,
+    /// The corner radius of rounded chips. Defaults to 8 points.
+    pub corner_radius: Option<CornerRadius>,
+    /// The space between the text of a chip and its edge. Defaults to a
+    /// horizontal padding of 3 points.
+    pub inner_margin: Option<Margin>,
+    /// The space around a chip, outside its background.
+    pub outer_margin: Option<Margin>,
+    /// The width and height of the chips, or `None` to fit their text.
+    pub size: Option<[f32; 2]>,
+    /// The silhouette of the chips.
+    pub shape: ChipShape,
+    /// Drop shadow painted below every chip.
+    pub shadow: Option<Shadow>,
+    /// Whether the chips are filled, outlined or elevated.
+    pub variant: ChipVariant,
+    /// Leading icon of every chip.
+    pub icon: Option<ChipIcon>,
+    /// Trailing icon of every chip, e.g. a dropdown arrow.
+    pub trailing_icon: Option<ChipIcon>,
+    /// The text style of the chips. Defaults to [`TextStyle::Body`].
+    pub text_style: Option<TextStyle>,
+}
+
+impl Default for ChipStyle {
+    fn default() -> Self {
+        Self {
+            bg_color: None,
+            text_color: None,
+            stroke: None,
+            hover_bg_color: None,
+            pressed_bg_color: None,
+            focus_stroke: None,
+            corner_radius: None,
+            inner_margin: None,
+            outer_margin: None,
+            size: Some(DEFAULT_CHIP_SIZE),
+            shape: ChipShape::default(),
+            shadow: None,
+            variant: ChipVariant::default(),
+            icon: None,
+            trailing_icon: None,
+            text_style: None,
+        }
+    }
+}
+
+impl ChipStyle {
+    /// Sets the background and text colors of the chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `bg_color` - The background color of the chips.
+    /// * `text_color` - The text color of the chips.
+    pub fn colors(mut self, bg_color: Color32, text_color: Color32) -> Self {
+        self.bg_color = Some(bg_color);
+        self.text_color = Some(text_color);
+        self
+    }
+
+    /// Sets the outline of the chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `stroke` - The outline, drawn inside the background.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Sets the background color of hovered chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The background color while hovered.
+    pub fn hover_bg_color(mut self, color: Color32) -> Self {
+        self.hover_bg_color = Some(color);
+        self
+    }
+
+    /// Sets the background color of pressed chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The background color while pressed.
+    pub fn pressed_bg_color(mut self, color: Color32) -> Self {
+        self.pressed_bg_color = Some(color);
+        self
+    }
+
+    /// Sets the outline of the chip being edited.
+    ///
+    /// # Arguments
+    ///
+    /// * `stroke` - The focus ring, drawn inside the background.
+    pub fn focus_stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.focus_stroke = Some(stroke.into());
+        self
+    }
+
+    /// Sets the corner radius of rounded chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `corner_radius` - The corner radius.
+    pub fn corner_radius(mut self, corner_radius: impl Into<CornerRadius>) -> Self {
+        self.corner_radius = Some(corner_radius.into());
+        self
+    }
+
+    /// Sets the space between the text of a chip and its edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - The inner margin of the chips.
+    pub fn inner_margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.inner_margin = Some(margin.into());
+        self
+    }
+
+    /// Sets the space around every chip, outside its background.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - The outer margin of the chips.
+    pub fn outer_margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.outer_margin = Some(margin.into());
+        self
+    }
+
+    /// Sets the size of the chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The width and height, or `None` to fit the text.
+    pub fn size(mut self, size: Option<[f32; 2]>) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the silhouette of the chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the chips.
+    pub fn shape(mut self, shape: ChipShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the drop shadow painted below every chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `shadow` - The shadow of the chips.
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Sets whether the chips are filled, outlined or elevated.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - The variant of the chips.
+    pub fn variant(mut self, variant: ChipVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the leading icon of every chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `icon` - The icon, or `None` for no icon.
+    pub fn icon(mut self, icon: Option<ChipIcon>) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Sets the trailing icon of every chip.
+    ///
+    /// # Arguments
+    ///
+    /// * `icon` - The icon, or `None` for no icon.
+    pub fn trailing_icon(mut self, icon: Option<ChipIcon>) -> Self {
+        self.trailing_icon = icon;
+        self
+    }
+
+    /// Sets the text style of the chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `text_style` - The text style, e.g. [`TextStyle::Small`].
+    pub fn text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = Some(text_style);
+        self
+    }
+}
+
+/// Visual settings of the widget frame and the layout of its chips. The chips
+/// themselves are styled with [`ChipStyle`].
+///
+/// # Examples
+///
+/// ```
+/// use egui::Color32;
+/// use egui::Shadow;
+/// use egui_chip::ChipEditBuilder;
+/// use egui_chip::ChipEditStyle;
+///
+/// let shadow = Shadow {
+///     offset: [0, 2],
+///     blur: 4,
+///     spread: 0,
+///     color: Color32::from_black_alpha(60),
+/// };
+/// let chip_edit = ChipEditBuilder::new(",")
+///     .unwrap()
+///     .edit_style(ChipEditStyle {
+///         widget_shadow: Some(shadow),
+///         ..Default::default()
+///     })
+///     .build();
+/// ```
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ChipEditStyle {
+    /// Drop shadow painted below the widget frame, for an elevated look.
+    pub widget_shadow: Option<Shadow>,
+    /// The horizontal and vertical gaps between chips and the gaps that
+    /// separate them.
+    pub chip_spacing: Vec2,
+    /// The corner radius of the widget frame. Defaults to square corners.
+    pub corner_radius: Option<CornerRadius>,
+    /// The space between the widget frame and the chips it contains.
+    pub widget_padding: Margin,
+    /// The color of the frame and the insertion marker while a chip is
+    /// dragged over a drop target. Defaults to the selection stroke color.
+    pub drop_highlight: Option<Color32>,
+}
+
+impl Default for ChipEditStyle {
+    fn default() -> Self {
+        Self {
+            widget_shadow: None,
+            chip_spacing: vec2(0., 1.),
+            corner_radius: None,
+            widget_padding: Margin::ZERO,
+            drop_highlight: None,
+        }
+    }
+}
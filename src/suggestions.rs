@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use egui::Id;
+use egui::Key;
+use egui::KeyboardShortcut;
+use egui::Modifiers;
+use egui::PopupCloseBehavior;
+use egui::Response;
+use egui::Ui;
+
+/// Maximum number of entries shown in the suggestion popup.
+pub(crate) const MAX_VISIBLE: usize = 10;
+
+/// Maximum number of accepted suggestions remembered for MRU ordering.
+const MAX_RECENT: usize = 10;
+
+/// Folds `text` for matching regardless of case and, with the `diacritics`
+/// feature, of diacritics.
+pub(crate) fn fold(text: &str) -> String {
+    #[cfg(feature = "diacritics")]
+    {
+        use unicode_normalization::UnicodeNormalization;
+        use unicode_normalization::char::is_combining_mark;
+
+        text.nfd()
+            .filter(|c| !is_combining_mark(*c))
+            .collect::<String>()
+            .to_lowercase()
+    }
+    #[cfg(not(feature = "diacritics"))]
+    text.to_lowercase()
+}
+
+/// The list of values offered in a popup while entering chips.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default = "Suggestions::new")]
+pub(crate) struct Suggestions {
+    /// All values that can be suggested, in the order they were given.
+    pub(crate) items: Vec<String>,
+    /// Recently accepted values, most recent first.
+    pub(crate) recent: Vec<String>,
+    /// Scores supplied by the app, e.g. usage counts. Matches are ordered by
+    /// descending score when any are set, values without one score zero.
+    #[serde(default)]
+    pub(crate) weights: HashMap<String, f32>,
+    /// Opens the popup with the full list even without typed text.
+    pub(crate) shortcut: KeyboardShortcut,
+    /// Index of the unit the popup is shown for, if it is open.
+    pub(crate) anchor: Option<usize>,
+    /// Index of the highlighted entry within the visible matches.
+    pub(crate) highlighted: usize,
+}
+
+impl Suggestions {
+    pub fn new() -> Self {
+        Self {
+            items: vec![],
+            recent: vec![],
+            weights: HashMap::new(),
+            shortcut: KeyboardShortcut::new(Modifiers::CTRL, Key::Space),
+            anchor: None,
+            highlighted: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn open(&mut self, anchor: usize) {
+        if self.anchor != Some(anchor) {
+            self.anchor = Some(anchor);
+            self.highlighted = 0;
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.anchor = None;
+        self.highlighted = 0;
+    }
+
+    /// Returns the items containing `query` regardless of case, skipping the
+    /// ones `exclude` returns `true` for. Items are ordered by weight if any
+    /// are set and otherwise, or among equal weights, recently used ones
+    /// come first.
+    pub fn matches(&self, query: &str, exclude: impl Fn(&str) -> bool) -> Vec<String> {
+        let query = fold(query);
+        let recent = self.recent.iter().filter(|item| self.items.contains(item));
+        let rest = self.items.iter().filter(|item| !self.recent.contains(item));
+        let mut matches: Vec<&String> = recent
+            .chain(rest)
+            .filter(|item| fold(item).contains(&query) && !exclude(item))
+            .collect();
+        if !self.weights.is_empty() {
+            let weight = |item: &String| self.weights.get(item).copied().unwrap_or(0.);
+            matches.sort_by(|a, b| weight(b).total_cmp(&weight(a)));
+        }
+        matches.into_iter().take(MAX_VISIBLE).cloned().collect()
+    }
+
+    /// Moves `item` to the front of the recently used list.
+    pub fn remember(&mut self, item: &str) {
+        self.recent.retain(|r| r != item);
+        self.recent.insert(0, item.to_owned());
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    /// Shows `matches` in the popup `popup_id` below `anchor` and returns the
+    /// index of the entry the user picked with the pointer or the keyboard, if
+    /// any.
+    pub fn show(
+        &mut self,
+        ui: &Ui,
+        popup_id: Id,
+        anchor: &Response,
+        matches: &[String],
+    ) -> Option<usize> {
+        let (down, up, enter, escape) = ui.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.key_pressed(Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+        if escape {
+            self.close();
+            ui.memory_mut(|m| m.close_popup());
+            return None;
+        }
+        if down {
+            self.highlighted = (self.highlighted + 1).min(matches.len() - 1);
+        }
+        if up {
+            self.highlighted = self.highlighted.saturating_sub(1);
+        }
+        self.highlighted = self.highlighted.min(matches.len() - 1);
+
+        let clicked = egui::popup_below_widget(
+            ui,
+            popup_id,
+            anchor,
+            PopupCloseBehavior::CloseOnClickOutside,
+            |ui| {
+                let mut clicked = None;
+                for (index, item) in matches.iter().enumerate() {
+                    if ui
+                        .selectable_label(index == self.highlighted, item)
+                        .clicked()
+                    {
+                        clicked = Some(index);
+                    }
+                }
+                clicked
+            },
+        )
+        .flatten();
+
+        let picked = clicked.or_else(|| enter.then_some(self.highlighted));
+        if picked.is_some() {
+            self.close();
+            ui.memory_mut(|m| m.close_popup());
+        }
+        picked
+    }
+}
@@ -0,0 +1,80 @@
+use egui::Align;
+use egui::Direction;
+use egui::Layout;
+use egui::Response;
+use egui::Ui;
+use egui::Vec2;
+use egui::Widget;
+
+use crate::chip::Chip;
+use crate::style::ChipEditStyle;
+use crate::style::ChipStyle;
+
+/// A read-only list of chips.
+///
+/// Paints the chips with the styling of a chip editor but without any
+/// editors, focus handling or interaction, which keeps it cheap enough for
+/// showing tags in every row of a long list.
+///
+/// # Examples
+///
+/// ```
+/// use egui_chip::ChipEditBuilder;
+/// use egui_chip::ChipSummary;
+///
+/// let chip_edit = ChipEditBuilder::new(",")
+///     .unwrap()
+///     .texts(["rust", "egui"])
+///     .build();
+/// let tags = vec!["rust".to_owned(), "egui".to_owned()];
+/// # egui::__run_test_ui(|ui| {
+/// // Styled like the editor.
+/// ui.add(chip_edit.summary());
+/// // Default styling.
+/// ui.add(ChipSummary::new(&tags));
+/// # });
+/// ```
+pub struct ChipSummary<'a> {
+    texts: &'a [String],
+    /// Carries the styling shared by all chips.
+    chip: Chip,
+    spacing: Vec2,
+}
+
+impl<'a> ChipSummary<'a> {
+    /// Creates a summary of `texts` with the default styling.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts of the chips.
+    pub fn new(texts: &'a [String]) -> Self {
+        Self {
+            texts,
+            chip: Chip::new_chip(ChipStyle::default()),
+            spacing: ChipEditStyle::default().chip_spacing,
+        }
+    }
+
+    /// Creates a summary of `texts` styled like the chips of an editor.
+    pub(crate) fn styled(texts: &'a [String], chip: Chip, spacing: Vec2) -> Self {
+        Self {
+            texts,
+            chip,
+            spacing,
+        }
+    }
+}
+
+impl Widget for ChipSummary<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let layout = Layout::from_main_dir_and_cross_align(Direction::LeftToRight, Align::TOP)
+            .with_main_wrap(true);
+        ui.with_layout(layout, |ui| {
+            ui.spacing_mut().item_spacing = self.spacing;
+            for text in self.texts {
+                self.chip.paint_static(ui, text);
+            }
+        })
+        .response
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use egui::Response;
+use egui::Ui;
+use egui::Widget;
+
+use crate::ChipEdit;
+use crate::ChipEditOutput;
+use crate::hook::Hook;
+
+/// A [`ChipEdit`] whose chips hold values of type `T`, created with
+/// [`ChipEditBuilder::build_typed`](crate::ChipEditBuilder::build_typed).
+///
+/// The chips are still edited as text. A chip whose text does not parse as a
+/// `T` is drawn in the error color and left out of [`Self::values`].
+///
+/// # Examples
+///
+/// ```
+/// use std::net::IpAddr;
+///
+/// use egui_chip::ChipEditBuilder;
+/// use egui_chip::TypedChipEdit;
+///
+/// let mut hosts: TypedChipEdit<IpAddr> = ChipEditBuilder::new(",")
+///     .unwrap()
+///     .texts(["127.0.0.1", "::1", "localhost"])
+///     .build_typed();
+/// // "localhost" is not an address.
+/// assert_eq!(hosts.values().len(), 2);
+/// hosts.push("10.0.0.1".parse().unwrap());
+/// ```
+pub struct TypedChipEdit<T> {
+    chip_edit: ChipEdit,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T: FromStr + Display + 'static> TypedChipEdit<T> {
+    /// Shows the widget.
+    pub fn show(&mut self, ui: &mut Ui) -> ChipEditOutput {
+        self.chip_edit.show(ui)
+    }
+
+    /// Returns the values of the chips that parse as a `T`.
+    pub fn values(&self) -> Vec<T> {
+        self.parsed().filter_map(Result::ok).collect()
+    }
+
+    /// Returns the value, or the parse error, of every chip.
+    pub fn parsed(&self) -> impl Iterator<Item = Result<T, T::Err>> + '_ {
+        self.chip_edit.iter().map(str::parse)
+    }
+
+    /// Returns `true` if every chip parses as a `T`.
+    pub fn is_valid(&self) -> bool {
+        self.parsed().all(|value| value.is_ok())
+    }
+
+    /// Replaces the chips with `values`.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the new chips.
+    pub fn set_values(&mut self, values: impl IntoIterator<Item = T>) {
+        self.chip_edit.set_text(values);
+    }
+
+    /// Appends a chip showing `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value of the new chip.
+    pub fn push(&mut self, value: T) {
+        self.chip_edit.push(value);
+    }
+
+    /// Returns the underlying text editor.
+    pub fn chip_edit(&self) -> &ChipEdit {
+        &self.chip_edit
+    }
+
+    /// Returns the underlying text editor, e.g. to lock or relabel chips.
+    pub fn chip_edit_mut(&mut self) -> &mut ChipEdit {
+        &mut self.chip_edit
+    }
+
+    /// Returns the underlying text editor, e.g. to persist it. Convert it back
+    /// with [`From`] after loading.
+    pub fn into_inner(self) -> ChipEdit {
+        self.chip_edit
+    }
+}
+
+impl<T: FromStr + Display + 'static> From<ChipEdit> for TypedChipEdit<T> {
+    fn from(mut chip_edit: ChipEdit) -> Self {
+        chip_edit.unowned.invalid_chip_if =
+            Some(Hook(Arc::new(|text: &str| text.parse::<T>().is_err())));
+        Self {
+            chip_edit,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T: FromStr + Display + 'static> Widget for &mut TypedChipEdit<T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
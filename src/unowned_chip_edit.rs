@@ -1,20 +1,81 @@
 use std::fmt::Display;
 
 use egui::Align;
+use egui::Align2;
+use egui::Button;
 use egui::Color32;
 use egui::Direction;
+use egui::Event;
+use egui::EventFilter;
+use egui::FontId;
+use egui::Id;
+use egui::ImageSource;
+use egui::Key;
+use egui::KeyboardShortcut;
 use egui::Layout;
+use egui::Modifiers;
+use egui::OpenUrl;
+use egui::PointerButton;
+use egui::Rect;
 use egui::Response;
-use egui::RichText;
+use egui::Sense;
+use egui::Shadow;
 use egui::Stroke;
+use egui::StrokeKind;
+use egui::TextEdit;
 use egui::Ui;
+use egui::Vec2;
+use egui::Widget;
+use egui::scroll_area::ScrollAreaOutput;
 use egui::vec2;
 
+use crate::BlurPolicy;
+use crate::ChipEditError;
 use crate::ChipEditOutput;
+use crate::ChipEditStyle;
+use crate::ChipEvent;
+use crate::ChipIcon;
+use crate::ChipRenderer;
+use crate::ChipSort;
+use crate::ChipStyle;
+use crate::ChipSummary;
+use crate::ChipVariant;
+use crate::CommittedChip;
+use crate::DefaultChipRenderer;
 use crate::chip::Chip;
 use crate::chip::ChipKind;
-use crate::chip::DEFAULT_CHIP_SIZE;
+use crate::chip::link_url;
+use crate::chip::range_bounds;
+use crate::color::pick_color;
+use crate::color::split_color;
+use crate::date::pick_date;
+use crate::date::split_date;
+use crate::drop::DropTarget;
+use crate::filter::holds_separator;
+use crate::hook::ActivateFn;
+use crate::hook::CharFilterFn;
+use crate::hook::ChipColorFn;
+use crate::hook::ChipEqFn;
+use crate::hook::ChipUiFn;
+use crate::hook::ChipValidateFn;
+use crate::hook::CommitFn;
+use crate::hook::ContextMenuFn;
+use crate::hook::Hook;
+use crate::hook::HoverUiFn;
+use crate::hook::IconFn;
+use crate::hook::ImageFn;
+use crate::hook::LockedFn;
+use crate::hook::NormalizeFn;
+use crate::hook::TooltipFn;
+use crate::hook::ValidateFn;
+use crate::import::Import;
+use crate::mention::Mention;
+use crate::mention::Mentions;
+use crate::selection::Selection;
+use crate::sort::save_sort;
 use crate::state::State;
+use crate::style::edge_fade;
+use crate::suggestions::Suggestions;
 
 /// Creates a chip style textbox from mutable reference to texts.
 ///
@@ -22,6 +83,7 @@ use crate::state::State;
 /// Pressing backspace in empty chip deletes it.
 /// Lost focus from empty chip deletes it
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default = "UnownedChipEdit::saved_default")]
 pub struct UnownedChipEdit {
     /// The separator string used to split chip texts.
     pub(crate) separator: String,
@@ -33,24 +95,227 @@ pub struct UnownedChipEdit {
     pub(crate) widget_bg: Option<Color32>,
     /// The foreground color of the widget.
     pub(crate) widget_fg: Option<Color32>,
-    /// The background color of the chips.
-    pub(crate) chip_bg: Option<Color32>,
-    /// The foreground color of the chips.
-    pub(crate) chip_fg: Option<Color32>,
     /// The index of the focused chip, if any.
     pub(crate) focused: Option<usize>,
+    /// Set by [`Self::surrender_focus`]; the focused editor gives up keyboard
+    /// focus during the next `show`.
+    pub(crate) pending_surrender: bool,
     /// Whether the widget should have a frame.
     pub(crate) frame: bool,
-    /// The size of the chips.
-    pub(crate) chip_size: Option<[f32; 2]>,
+    /// Base id of the internal editors, derived from the builder's id salt.
+    /// Automatic ids are used when `None`.
+    pub(crate) id: Option<Id>,
+    /// The look of the chips.
+    pub(crate) chip_style: ChipStyle,
+    /// The look of the widget frame and the layout of the chips.
+    pub(crate) style: ChipEditStyle,
+    /// Whether the chips stay on one row that scrolls horizontally.
+    pub(crate) single_row: bool,
+    /// Whether chips can only be picked from the suggestions, see
+    /// [`ChipEditBuilder::fixed_vocabulary`](crate::ChipEditBuilder::fixed_vocabulary).
+    pub(crate) fixed_vocabulary: bool,
+    /// Whether chips ending with an ISO date are picked from a calendar, see
+    /// [`ChipEditBuilder::date_picker`](crate::ChipEditBuilder::date_picker).
+    pub(crate) date_picker: bool,
+    /// Whether chips ending with a hex color show a swatch and pick it, see
+    /// [`ChipEditBuilder::color_picker`](crate::ChipEditBuilder::color_picker).
+    pub(crate) color_picker: bool,
+    /// Whether chips holding a URL are drawn as links that open on Ctrl+click,
+    /// see [`ChipEditBuilder::open_links`](crate::ChipEditBuilder::open_links).
+    pub(crate) open_links: bool,
+    /// Whether separators typed between double quotes do not split chips, for
+    /// filters such as `subject:"q3 plan"`.
+    pub(crate) quotes: bool,
+    /// The prefix of words committed as chips out of free text, see
+    /// [`ChipEditBuilder::hashtags`](crate::ChipEditBuilder::hashtags).
+    pub(crate) hashtag_prefix: Option<String>,
+    /// Whether the widget is shown inside a window or popup, see
+    /// [`ChipEditBuilder::in_window`](crate::ChipEditBuilder::in_window).
+    pub(crate) in_window: bool,
+    /// Turns payloads dropped on the widget into chips.
+    pub(crate) drop_target: DropTarget,
+    /// The maximum number of chars of all chips together.
+    pub(crate) max_total_chars: Option<usize>,
+    /// The pointer button that deletes the clicked chip.
+    pub(crate) delete_button: Option<PointerButton>,
+    /// Whether clicking the leading or trailing icon of a chip deletes it.
+    pub(crate) delete_on_icon_click: bool,
+    /// Whether the widget ignores its minimum size while it is empty and
+    /// unfocused.
+    pub(crate) shrink_when_empty: bool,
+    /// Ghost chips shown while there are no chips.
+    pub(crate) examples: Vec<String>,
+    /// Whether every chip underlines a character that focuses it with Alt.
+    pub(crate) mnemonics: bool,
+    /// Whether the chips can be edited, deleted and focused.
+    pub(crate) interactive: bool,
+    /// Whether the widget was marked invalid by the app.
+    pub(crate) invalid: bool,
+    /// What happens to text still being typed when the focus leaves the
+    /// widget.
+    #[serde(default)]
+    pub(crate) blur_policy: BlurPolicy,
+    /// Whether committed chips that duplicate another chip are dropped.
+    pub(crate) dedup: bool,
+    /// Decides whether two chips are duplicates, instead of comparing them.
+    #[serde(skip)]
+    pub(crate) dedup_eq: Option<Hook<ChipEqFn>>,
+    /// The order the chips are kept in, if any.
+    #[serde(serialize_with = "save_sort")]
+    pub(crate) sort: Option<ChipSort>,
+    /// The width of the widget, instead of the available width.
+    pub(crate) desired_width: Option<f32>,
+    /// The minimum size of the widget.
+    pub(crate) min_size: Option<Vec2>,
+    /// The maximum size of the widget. Chips beyond the maximum height scroll
+    /// vertically.
+    pub(crate) max_size: Option<Vec2>,
+    /// The number of wrapped rows after which the chips scroll vertically.
+    pub(crate) max_rows: Option<usize>,
+    /// The number of chips shown while the widget is collapsed, followed by
+    /// a chip counting the hidden ones.
+    pub(crate) collapse_after: Option<usize>,
+    /// Whether chips beyond the maximum height are edited in a window opened
+    /// from a "Show all" link instead of scrolling.
+    pub(crate) show_all: bool,
+    /// Whether the window with all chips is open.
+    #[serde(skip)]
+    pub(crate) show_all_open: bool,
+    /// The link and window in which chips can be pasted in bulk.
+    pub(crate) import: Import,
+    /// Whether the user expanded the collapsed chips.
+    #[serde(skip)]
+    pub(crate) expanded: bool,
+    /// Toggles quick-delete mode, in which number keys delete the visible
+    /// chips. Disabled when `None`.
+    pub(crate) quick_delete_shortcut: Option<KeyboardShortcut>,
+    /// Move the focused chip one position left and right. Disabled when
+    /// `None`.
+    pub(crate) move_shortcuts: Option<(KeyboardShortcut, KeyboardShortcut)>,
+    /// The chips picked with Ctrl+click or Shift+click.
+    pub(crate) selection: Selection,
+    /// Whether Backspace highlights the chip before a gap before deleting it,
+    /// see
+    /// [`ChipEditBuilder::two_stage_backspace`](crate::ChipEditBuilder::two_stage_backspace).
+    pub(crate) two_stage_backspace: bool,
+    /// The unit of the chip the next Backspace deletes.
+    #[serde(skip)]
+    pub(crate) backspace_armed: Option<usize>,
+    /// The unit of the chip being edited and its text before the edit, which
+    /// Escape restores.
+    #[serde(skip)]
+    pub(crate) edit_snapshot: Option<(usize, String)>,
+    /// The changes to the chips made during the current `show`.
+    #[serde(skip)]
+    pub(crate) events: Vec<ChipEvent>,
+    /// Whether quick-delete mode is active.
+    #[serde(skip)]
+    pub(crate) quick_deleting: bool,
+    /// Whether the gap after the last chip claims the remaining width of its
+    /// row.
+    pub(crate) grow_trailing_gap: bool,
+    /// Whether units outside the clip rect are skipped instead of shown.
+    pub(crate) virtualized: bool,
+    /// Whether texts of the form `min..max` are shown as range chips.
+    pub(crate) range_chips: bool,
+    /// Characters in pasted text that are treated like the separator, e.g. to
+    /// paste a spreadsheet column.
+    pub(crate) paste_separators: Vec<char>,
+    /// Characters that are treated like the separator when typed.
+    pub(crate) typed_separators: Vec<char>,
+    /// Keys that commit the chip being typed, or the text typed in a gap, as
+    /// chips.
+    pub(crate) commit_keys: Vec<Key>,
+    /// Decides which typed or pasted characters are kept.
+    #[serde(skip)]
+    pub(crate) char_filter: Option<Hook<CharFilterFn>>,
     /// texts len during previous iteration
     /// We use this to detect if we need to rebuild the widget
     pub(crate) texts_len: usize,
 
-    /// Leading `icon` char in chip
-    // TODO: Fix serde
+    /// Values offered in a popup while entering chips.
+    pub(crate) suggestions: Suggestions,
+    /// People offered in a popup while typing a mention.
+    #[serde(default)]
+    pub(crate) mentions: Mentions,
+
+    /// Screen rects of the chips as laid out during the last `show`.
+    #[serde(skip)]
+    pub(crate) chip_rects: Vec<Rect>,
+
+    /// The rect of the whole widget during the last `show`, where it can be
+    /// focused with Tab.
+    #[serde(skip)]
+    pub(crate) entry_rect: Rect,
+    /// Whether the widget itself, rather than one of its editors, has focus.
+    #[serde(skip)]
+    pub(crate) entry_focused: bool,
+
+    /// The rect of every unit during the last `show`, relative to the first
+    /// unit.
+    #[serde(skip)]
+    pub(crate) unit_rects: Vec<Rect>,
+
+    /// Renders a preview when a chip is hovered.
+    #[serde(skip)]
+    pub(crate) hover_ui: Option<Hook<HoverUiFn>>,
+
+    /// Draws the chips and gaps instead of the built-in look.
+    #[serde(skip)]
+    pub(crate) renderer: Option<Hook<dyn ChipRenderer>>,
+
+    /// Draws the content of every chip instead of its label.
+    #[serde(skip)]
+    pub(crate) chip_ui_fn: Option<Hook<ChipUiFn>>,
+
+    /// Renders the right-click menu of a chip.
+    #[serde(skip)]
+    pub(crate) context_menu_fn: Option<Hook<ContextMenuFn>>,
+
+    /// Picks the tooltip of a hovered chip from its text and index.
+    #[serde(skip)]
+    pub(crate) tooltip_fn: Option<Hook<TooltipFn>>,
+
+    /// Checks whether the values as a whole are invalid.
+    #[serde(skip)]
+    pub(crate) invalid_if: Option<Hook<ValidateFn>>,
+
+    /// Checks whether a single chip is invalid.
+    #[serde(skip)]
+    pub(crate) invalid_chip_if: Option<Hook<ChipValidateFn>>,
+
+    /// Turns committed text into chips instead of splitting on the separator.
+    #[serde(skip)]
+    pub(crate) commit_fn: Option<Hook<CommitFn>>,
+    /// Rewrites the text of every committed chip.
+    #[serde(skip)]
+    pub(crate) normalize_fn: Option<Hook<NormalizeFn>>,
+
+    /// Called when Enter is pressed on a focused chip that is not edited.
+    #[serde(skip)]
+    pub(crate) activate_fn: Option<Hook<ActivateFn>>,
+
+    /// Locks chips by their index and text, in addition to
+    /// [`Self::lock`].
+    #[serde(skip)]
+    pub(crate) locked_fn: Option<Hook<LockedFn>>,
+
+    /// Picks the icon of every chip from its text and index.
+    #[serde(skip)]
+    pub(crate) icon_fn: Option<Hook<IconFn>>,
+
+    /// Picks the image of every chip from its text and index.
+    #[serde(skip)]
+    pub(crate) image_fn: Option<Hook<ImageFn>>,
+
+    /// Picks the colors of every chip from its text and index.
+    #[serde(skip)]
+    pub(crate) chip_color_fn: Option<Hook<ChipColorFn>>,
+
+    /// Whether chips were added, removed or moved since the last `show`
+    /// reported it, including by the app between frames.
     #[serde(skip)]
-    pub(crate) icon: Option<RichText>,
+    pub(crate) restructured: bool,
 }
 
 impl Display for UnownedChipEdit {
@@ -68,10 +333,10 @@ impl UnownedChipEdit {
     ///
     /// # Errors
     ///
-    /// Returns an error if the separator is empty.
-    pub fn new(separator: &str) -> Result<Self, String> {
+    /// Returns [`ChipEditError::EmptySeparator`] if the separator is empty.
+    pub fn new(separator: &str) -> Result<Self, ChipEditError> {
         if separator.is_empty() {
-            Err("separator cannot be empty".to_owned())
+            Err(ChipEditError::EmptySeparator)
         } else {
             let mut ret = Self {
                 separator: separator.into(),
@@ -79,27 +344,101 @@ impl UnownedChipEdit {
                 units: vec![],
                 widget_bg: None,
                 widget_fg: None,
-                chip_bg: None,
-                chip_fg: None,
                 focused: None,
+                pending_surrender: false,
                 frame: true,
-                chip_size: Some(DEFAULT_CHIP_SIZE),
-                icon: None,
+                id: None,
+                chip_style: ChipStyle::default(),
+                style: ChipEditStyle::default(),
+                single_row: false,
+                fixed_vocabulary: false,
+                date_picker: false,
+                color_picker: false,
+                open_links: false,
+                quotes: false,
+                hashtag_prefix: None,
+                in_window: false,
+                drop_target: DropTarget::default(),
+                max_total_chars: None,
+                delete_button: None,
+                delete_on_icon_click: false,
+                shrink_when_empty: false,
+                examples: Vec::new(),
+                mnemonics: false,
+                interactive: true,
+                invalid: false,
+                blur_policy: BlurPolicy::default(),
+                dedup: false,
+                dedup_eq: None,
+                sort: None,
+                desired_width: None,
+                min_size: None,
+                max_size: None,
+                max_rows: None,
+                collapse_after: None,
+                expanded: false,
+                show_all: false,
+                show_all_open: false,
+                import: Import::default(),
+                grow_trailing_gap: true,
+                quick_delete_shortcut: None,
+                selection: Selection::default(),
+                two_stage_backspace: false,
+                backspace_armed: None,
+                edit_snapshot: None,
+                events: vec![],
+                move_shortcuts: None,
+                quick_deleting: false,
+                virtualized: false,
+                range_chips: false,
+                paste_separators: vec!['\n', '\t'],
+                typed_separators: vec![],
+                commit_keys: vec![Key::Enter],
+                char_filter: None,
+                suggestions: Suggestions::new(),
+                mentions: Mentions::default(),
+                chip_rects: vec![],
+                entry_rect: Rect::NOTHING,
+                entry_focused: false,
+                unit_rects: vec![],
+                hover_ui: None,
+                renderer: None,
+                chip_ui_fn: None,
+                context_menu_fn: None,
+                tooltip_fn: None,
+                commit_fn: None,
+                normalize_fn: None,
+                chip_color_fn: None,
+                icon_fn: None,
+                image_fn: None,
+                activate_fn: None,
+                locked_fn: None,
+                invalid_if: None,
+                invalid_chip_if: None,
                 texts_len: 0,
+                restructured: false,
             };
             ret.rebuild(&mut []);
             Ok(ret)
         }
     }
 
-    pub(crate) fn default_fg_stroke(&self, ui: &Ui) -> impl Into<Stroke> {
+    /// Fills in the fields missing from a widget saved by an earlier
+    /// version. The separator is always saved.
+    fn saved_default() -> Self {
+        Self::new(",").expect("the separator is not empty")
+    }
+
+    pub(crate) fn default_fg_stroke(&self, ui: &Ui, invalid: bool) -> impl Into<Stroke> {
         Stroke {
             width: if self.frame {
                 ui.visuals().selection.stroke.width
             } else {
                 0.
             },
-            color: if self.focused.is_some() {
+            color: if invalid {
+                ui.visuals().error_fg_color
+            } else if self.focused.is_some() || self.entry_focused {
                 Self::default_widget_fg_color(ui)
             } else {
                 ui.style().visuals.faint_bg_color
@@ -117,184 +456,2869 @@ impl UnownedChipEdit {
     ///
     /// A `ChipEditOutput` containing the result of the widget interaction.
     pub fn show(&mut self, ui: &mut Ui, texts: &mut Vec<String>) -> ChipEditOutput {
-        if self.texts_len != texts.len() {
-            self.rebuild(texts);
-        }
-        let max_index = self.units.len() - 1;
+        let had_focus = self.focused.is_some() || self.entry_focused;
+        let selected_before = self.selected_indices();
+        // Changes made by the app between frames are not reported.
+        self.events.clear();
+        self.begin_frame(ui, texts);
+        let (shortcut_changed, deleted) = self.apply_shortcuts(ui, texts);
+        let mut state = self.frame_state(ui);
         let mut outputs = Vec::with_capacity(self.units.len());
+        let invalid = self.is_invalid(texts);
+        let mut ret = self.show_chips(ui, texts, invalid, &mut state, &mut outputs);
+        let added = self.show_extras(ui, &ret.response, texts);
 
-        let widget_bg = self.widget_bg.unwrap_or(Self::default_widget_bg_color(ui));
+        // Retain focus history for the next iteration
+        let blurred = (state.focus_changed && state.focus.is_none()) || self.pending_surrender;
+        let abandoned = self.end_edit(texts, &mut state, blurred);
+        self.update_focus(texts, &mut state, had_focus);
+
+        let mut structure_changed = shortcut_changed || added || abandoned;
+        let popup_id = self.id.unwrap_or(ret.response.id);
+        if self.update_popups(ui, popup_id, &outputs, texts) {
+            // Chip indices moved; the pending edits no longer apply.
+            state.split = None;
+            state.merge = None;
+            state.delete = None;
+            structure_changed = true;
+        }
+        structure_changed |= self.apply_edits(texts, &state);
+        if !structure_changed && self.commit_hashtag(texts, &outputs) {
+            structure_changed = true;
+        }
+        if !structure_changed && self.delete_clicked_icon(texts, &outputs) {
+            structure_changed = true;
+        }
+        let pointer_deleted = self
+            .delete_button
+            .filter(|_| !structure_changed)
+            .and_then(|button| self.delete_clicked(texts, &outputs, button));
+        structure_changed |= pointer_deleted.is_some();
+        structure_changed =
+            self.settle_chips(texts, blurred, structure_changed, state.focus_changed);
+        self.remember_frame(ui, texts, &state, &outputs, structure_changed);
+
+        let chip_responses = outputs
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|o| o.response.clone())
+            .collect();
+        outputs.into_iter().for_each(|o| ret.union(o));
+        ret.chip_responses = chip_responses;
+        ret.focused_index = self.focused_index();
+        ret.deleted = deleted;
+        ret.events = std::mem::take(&mut self.events);
+        ret.selected = self.selected_indices();
+        ret.selection_changed = ret.selected != selected_before;
+        ret.structure_changed = structure_changed || std::mem::take(&mut self.restructured);
+        if ret.any_changed() {
+            ret.response.mark_changed();
+        }
+        ret.pointer_deleted = pointer_deleted;
+        ret.invalid = invalid;
+        ret.wants_previous_widget = state.leave_backward;
+        ret.wants_next_widget = state.leave_forward;
+        ret
+    }
+
+    /// Catches up with the changes made by the app and handles the input
+    /// meant for the widget as a whole, before the units are shown.
+    fn begin_frame(&mut self, ui: &mut Ui, texts: &mut Vec<String>) {
+        self.sync(texts);
+        if self.mnemonics {
+            self.assign_mnemonics(texts);
+        }
+        if self.interactive {
+            self.focus_entry(ui, texts);
+            if self.mnemonics {
+                self.press_mnemonic(ui);
+            }
+        } else {
+            self.focused = None;
+            self.entry_focused = false;
+        }
+        if self.focused.is_some() {
+            self.rewrite_input(ui);
+        }
+    }
+
+    /// Applies the shortcuts that act on whole chips. Uses the chip rects of
+    /// the last frame, before the editors see the number keys.
+    ///
+    /// Returns whether chips were added, removed or moved, and the texts of
+    /// the selected chips deleted at once.
+    fn apply_shortcuts(&mut self, ui: &Ui, texts: &mut Vec<String>) -> (bool, Vec<String>) {
+        let quick_deleted = self.quick_delete(ui, texts);
+        let moved = self.move_focused_chip(ui, texts);
+        let deleted = if self.selection.enabled {
+            self.select_all_or_delete(ui, texts)
+        } else {
+            vec![]
+        };
+        let entered = self.commit_on_key(ui, texts);
+        (
+            quick_deleted || moved || !deleted.is_empty() || entered,
+            deleted,
+        )
+    }
+
+    /// Returns the state the units share during this frame.
+    fn frame_state(&mut self, ui: &Ui) -> State {
         let mut state = State::from(&self.focused);
+        state.fixed_vocabulary = self.fixed_vocabulary;
+        state.free_text = self.hashtag_prefix.is_some();
+        state.select_click = self.selection.click_selects(ui);
+        state.two_stage_backspace = self.two_stage_backspace;
+        state.armed = self.backspace_armed;
+        state.quotes = self.quotes;
+        state.snapshot = self.edit_snapshot.take();
+        state
+    }
+
+    /// Shows the frame with the units, laid out in wrapped rows or a single
+    /// row, and the window with all chips while it is open.
+    fn show_chips(
+        &mut self,
+        ui: &mut Ui,
+        texts: &mut [String],
+        invalid: bool,
+        state: &mut State,
+        outputs: &mut Vec<ChipEditOutput>,
+    ) -> ChipEditOutput {
+        self.chip_rects.clear();
+        let widget_bg = self.widget_bg.unwrap_or(Self::default_widget_bg_color(ui));
         let layout = Layout::from_main_dir_and_cross_align(Direction::LeftToRight, Align::TOP)
-            .with_main_wrap(true)
+            .with_main_wrap(!self.single_row)
             .with_cross_justify(false);
-        let mut ret: ChipEditOutput = ui
-            .allocate_ui(vec2(ui.available_size_before_wrap().x, 20.), |ui| {
+        let min_size = self.min_size(texts);
+        let size = self.widget_size(ui, min_size);
+        let max_height = self.max_height(ui);
+        let ret: ChipEditOutput = ui
+            .allocate_ui(size, |ui| {
+                if !self.interactive {
+                    ui.disable();
+                }
                 ui.with_layout(layout, |ui| {
-                    ui.spacing_mut().item_spacing = egui::vec2(0., 1.0);
-                    egui::Frame::new()
-                        .fill(widget_bg)
-                        .stroke(self.default_fg_stroke(ui))
-                        .show(ui, |ui| {
-                            for (index, unit) in &mut self.units.iter_mut().enumerate() {
-                                let text = if let ChipKind::Separator = &mut unit.kind {
-                                    self.separator_text.get_mut(index / 2).unwrap()
-                                } else {
-                                    texts.get_mut(index / 2).unwrap()
-                                };
-                                let output = unit.show(ui, self.focused == Some(index), text);
-                                state.update(
-                                    max_index,
-                                    index,
-                                    unit,
-                                    &output,
-                                    &self.separator,
-                                    text,
-                                );
-                                if state.focus == Some(index) {
-                                    output.response.request_focus();
-                                }
-
-                                unit.update_position(&output, text);
-                                outputs.push(output);
-                            }
-                        });
+                    ui.spacing_mut().item_spacing = self.style.chip_spacing;
+                    self.frame(ui, widget_bg, invalid).show(ui, |ui| {
+                        if let Some(min_size) = min_size {
+                            ui.set_min_size(min_size);
+                        }
+                        if self.single_row {
+                            // A vertical wheel scrolls the row sideways, since there is
+                            // nothing else to scroll. Dragging anything but an editor
+                            // scrolls it too, and it glides on after a flick.
+                            ui.style_mut().always_scroll_the_only_direction = true;
+                            let scroll = egui::ScrollArea::horizontal()
+                                .drag_to_scroll(true)
+                                .auto_shrink([false, true])
+                                .show(ui, |ui| {
+                                    ui.horizontal(|ui| self.show_units(ui, texts, state, outputs))
+                                });
+                            Self::paint_fades(ui, &scroll, widget_bg);
+                        } else if let Some(max_height) = max_height {
+                            egui::ScrollArea::vertical()
+                                .max_height(max_height)
+                                .auto_shrink([false, true])
+                                .show(ui, |ui| {
+                                    ui.with_layout(layout, |ui| {
+                                        if self.show_all_open {
+                                            // The chips are edited in the window.
+                                            self.paint_static_units(ui, texts);
+                                        } else {
+                                            self.show_units(ui, texts, state, outputs)
+                                        }
+                                    })
+                                });
+                        } else {
+                            self.show_units(ui, texts, state, outputs);
+                        }
+                    });
                 });
             })
             .response
             .into();
+        self.entry_rect = ret.response.rect;
+        if self.show_all
+            && !self.show_all_open
+            && max_height.is_some_and(|max_height| self.content_height() > max_height)
+            && ui.link(format!("Show all ({})", texts.len())).clicked()
+        {
+            self.show_all_open = true;
+        }
+        if self.show_all_open {
+            let id = self.id.unwrap_or(ret.response.id).with("show_all");
+            let mut open = true;
+            egui::Window::new("All chips")
+                .id(id)
+                .open(&mut open)
+                .collapsible(false)
+                .default_width(size.x)
+                .show(ui.ctx(), |ui| {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        ui.with_layout(layout, |ui| {
+                            ui.spacing_mut().item_spacing = self.style.chip_spacing;
+                            self.show_units(ui, texts, state, outputs)
+                        })
+                    });
+                });
+            self.show_all_open = open;
+        }
+        ret
+    }
 
-        // Retain focus history for the next iteration
+    /// Shows what goes below the chips, i.e. the chars left, the "Import"
+    /// link and the quick-delete badges, and accepts payloads dropped on the
+    /// widget's `response`.
+    ///
+    /// Returns `true` if chips were dropped or imported.
+    fn show_extras(&mut self, ui: &mut Ui, response: &Response, texts: &mut Vec<String>) -> bool {
+        if let Some(chars_left) = self.chars_left(texts) {
+            ui.weak(match chars_left {
+                1 => "1 character left".to_owned(),
+                chars_left => format!("{chars_left} characters left"),
+            });
+        }
+        let dropped = self.drop_target.enabled && self.accept_drop(ui, response, texts);
+        let imported = self.import.enabled
+            && self.show_import(ui, self.id.unwrap_or(response.id).with("import"), texts);
+        if self.quick_deleting {
+            self.paint_quick_delete_badges(ui);
+        }
+        dropped || imported
+    }
+
+    /// Ends the edit cancelled with Escape, or discarded by the widget
+    /// losing focus under [`BlurPolicy::DiscardOnBlur`], by restoring the
+    /// text the chip had before it.
+    ///
+    /// Returns `true` if the chip being typed was dropped instead.
+    fn end_edit(&mut self, texts: &mut Vec<String>, state: &mut State, blurred: bool) -> bool {
+        // Escape meant for a popup does not cancel the edit.
+        let cancelled = state
+            .revert
+            .filter(|_| self.suggestions.anchor.is_none() && self.mentions.popup.anchor.is_none());
+        let discarded = state
+            .snapshot
+            .as_ref()
+            .map(|(unit, _)| *unit)
+            .filter(|_| blurred && self.blur_policy == BlurPolicy::DiscardOnBlur);
+        // The chip being typed was never committed, so cancelling or discarding
+        // it drops it rather than restoring the text it started with.
+        let abandoned = self
+            .units
+            .iter()
+            .position(|unit| unit.typing)
+            .filter(|unit| {
+                cancelled == Some(*unit) || blurred && self.blur_policy == BlurPolicy::DiscardOnBlur
+            });
+        if let Some(unit) = abandoned {
+            state.snapshot = None;
+            self.remove(texts, unit / 2);
+            // Chip indices moved; the pending edits no longer apply.
+            state.split = None;
+            state.merge = None;
+            state.delete = None;
+        } else if let Some(unit) = cancelled.or(discarded)
+            && let Some((_, before)) = state.snapshot.take()
+            && let Some(text) = texts.get_mut(unit / 2)
+        {
+            let edited = std::mem::replace(text, before);
+            self.events.extend(ChipEvent::edit(unit / 2, edited, text));
+        }
+        abandoned.is_some()
+    }
+
+    /// Moves the focus where the units sent it during this frame, committing
+    /// the chip it leaves.
+    fn update_focus(&mut self, texts: &mut [String], state: &mut State, had_focus: bool) {
+        if let Some(normalize_fn) = &self.normalize_fn
+            && state.focus_changed
+            && let Some(left) = self
+                .focused
+                .filter(|f| f % 2 == 1 && state.focus != Some(*f) && !self.units[*f].typing)
+            && let Some(text) = texts.get_mut(left / 2)
+        {
+            // The chip left by the focus is committed.
+            let normalized = normalize_fn(text);
+            let old = std::mem::replace(text, normalized);
+            self.events.extend(ChipEvent::edit(left / 2, old, text));
+        }
         if state.focus_changed {
             self.focused = state.focus;
         }
+        // Collapse again once the focus leaves the expanded widget, whether
+        // from a chip, a gap or the widget itself, e.g. with Tab.
+        if had_focus && self.focused.is_none() && !self.entry_focused {
+            self.expanded = false;
+        }
+        self.pending_surrender = false;
+        self.edit_snapshot = state
+            .snapshot
+            .take()
+            .filter(|(unit, _)| self.focused == Some(*unit));
+    }
 
-        if let Some(_index) = state.split {
-            self.split(texts);
+    /// Opens, shows and applies the mention and suggestion popups.
+    ///
+    /// Returns `true` if a person or a suggestion was picked, which changes
+    /// the chip indices.
+    fn update_popups(
+        &mut self,
+        ui: &Ui,
+        id: Id,
+        outputs: &[ChipEditOutput],
+        texts: &mut Vec<String>,
+    ) -> bool {
+        let mut picked = false;
+        if !self.mentions.is_empty() {
+            picked |= self.update_mentions(ui, id.with("mentions"), outputs, texts);
+        }
+        if !self.suggestions.is_empty() {
+            picked |= self.update_suggestions(ui, id.with("suggestions"), outputs, texts);
         }
+        picked
+    }
 
-        if state.merge.is_some() || state.delete.is_some() {
-            self.merge(
-                texts,
-                state.merge.unwrap_or((usize::MAX, usize::MAX)),
-                state.delete.unwrap_or(usize::MAX),
-            );
+    /// Applies the split, merge or delete asked for by the focused unit, and
+    /// commits the chip being typed once the focus moved on.
+    ///
+    /// Returns `true` if chips were added, removed or moved.
+    fn apply_edits(&mut self, texts: &mut Vec<String>, state: &State) -> bool {
+        let mut changed = false;
+        // Only the focused unit acts on keys, so at most one of these is set.
+        if let Some(unit) = state.split {
+            if self.units[unit].is_separator()
+                && !holds_separator(&self.separator_text[unit / 2], &self.separator, self.quotes)
+            {
+                self.start_typing(texts, unit);
+            } else {
+                self.split(texts, unit);
+            }
+            changed = true;
+        } else if let Some((a, b)) = state.merge
+            && !self.units[a].read_only
+            && !self.units[b].read_only
+        {
+            changed = self.merge(texts, (a, b));
+        } else if let Some(unit) = state.delete
+            && !self.units[unit].read_only
+        {
+            self.remove(texts, unit / 2);
+            changed = true;
+        }
+        // The chip being typed is committed once the focus moves on.
+        if self
+            .units
+            .iter()
+            .position(|unit| unit.typing)
+            .is_some_and(|unit| self.focused != Some(unit))
+            && self.finish_typing(texts)
+        {
+            changed = true;
         }
+        changed
+    }
 
-        outputs.into_iter().for_each(|o| ret.union(o));
-        ret
+    /// Turns a hashtag finished in the focused gap into a chip, see
+    /// [`ChipEditBuilder::hashtags`](crate::ChipEditBuilder::hashtags).
+    ///
+    /// Returns `true` if a chip was added.
+    fn commit_hashtag(&mut self, texts: &mut Vec<String>, outputs: &[ChipEditOutput]) -> bool {
+        let Some(prefix) = &self.hashtag_prefix else {
+            return false;
+        };
+        let Some(unit) = self
+            .focused
+            .filter(|f| f % 2 == 0 && outputs.get(*f).is_some_and(|o| o.response.changed()))
+        else {
+            return false;
+        };
+        let Some(start) = hashtag_at_end(&self.separator_text[unit / 2], prefix) else {
+            return false;
+        };
+        let gap = &mut self.separator_text[unit / 2];
+        let hashtag = gap[start..].trim_end().to_owned();
+        gap.truncate(start);
+        self.insert_chips(texts, unit / 2, vec![CommittedChip::new(hashtag)]);
+        // Continue typing in the gap after the new chip.
+        self.focused = Some(unit + 2);
+        true
     }
 
-    /// Rebuilds the `UnownedChipEdit` widget with the given texts.
+    /// Deletes the chip whose leading or trailing icon was clicked, if
+    /// [`ChipEditBuilder::delete_on_icon_click`](crate::ChipEditBuilder::delete_on_icon_click)
+    /// is set.
     ///
-    /// # Arguments
+    /// Returns `true` if a chip was deleted.
+    fn delete_clicked_icon(&mut self, texts: &mut Vec<String>, outputs: &[ChipEditOutput]) -> bool {
+        if !self.delete_on_icon_click {
+            return false;
+        }
+        let Some(index) = outputs.iter().find_map(ChipEditOutput::icon_clicked) else {
+            return false;
+        };
+        if self.units[index * 2 + 1].read_only {
+            return false;
+        }
+        self.remove(texts, index);
+        true
+    }
+
+    /// Deletes the chip clicked with `button`.
     ///
-    /// * `texts` - A vector of strings representing the texts for the chips.
-    pub(crate) fn rebuild(&mut self, texts: &mut [String]) {
-        self.units.clear();
-        let len = texts.len();
-        self.texts_len = len;
+    /// Returns the index of the deleted chip.
+    fn delete_clicked(
+        &mut self,
+        texts: &mut Vec<String>,
+        outputs: &[ChipEditOutput],
+        button: PointerButton,
+    ) -> Option<usize> {
+        let unit = (1..outputs.len()).step_by(2).find(|&unit| {
+            outputs[unit].response.clicked_by(button) && !self.units[unit].read_only
+        })?;
+        self.remove(texts, unit / 2);
+        Some(unit / 2)
+    }
 
-        self.units.push(Chip::new_separator());
-        self.separator_text.push("".to_owned());
-        for (index, _) in texts.iter_mut().enumerate() {
-            let mut chip = Chip::new_chip(self.chip_size, self.icon.clone());
-            chip.bg_color = self.chip_bg;
-            chip.text_color = self.chip_fg;
-            self.units.push(chip);
-            if index != len - 1 {
-                self.units.push(Chip::new_separator());
-                self.separator_text.push("".to_owned());
-            }
+    /// Applies the blur policy once the focus left the widget, then drops the
+    /// duplicates and restores the order of the chips if they or the focus
+    /// `changed`.
+    ///
+    /// Returns whether chips were added, removed or moved during this frame,
+    /// including the `changed` ones.
+    fn settle_chips(
+        &mut self,
+        texts: &mut Vec<String>,
+        blurred: bool,
+        mut changed: bool,
+        focus_changed: bool,
+    ) -> bool {
+        if blurred && (self.fixed_vocabulary || self.blur_policy == BlurPolicy::DiscardOnBlur) {
+            // Searches that matched nothing, or discarded text, are dropped.
+            self.separator_text.iter_mut().for_each(String::clear);
+        } else if blurred
+            && self.blur_policy == BlurPolicy::CommitOnBlur
+            && self.commit_all_pending(texts)
+        {
+            changed = true;
         }
-        if len > 0 {
-            self.units.push(Chip::new_separator());
-            self.separator_text.push("".to_owned());
+        if self.dedup && (changed || focus_changed) && self.remove_duplicates(texts) {
+            changed = true;
         }
+        if let Some(sort) = self.sort
+            && (changed || focus_changed)
+            && self.sort_chips(texts, sort)
+        {
+            changed = true;
+        }
+        changed
     }
 
-    /// Sets the texts for the chips.
+    /// Remembers what the next frame needs to know about the keys and the
+    /// text selection of this one.
+    fn remember_frame(
+        &mut self,
+        ui: &Ui,
+        texts: &[String],
+        state: &State,
+        outputs: &[ChipEditOutput],
+        structure_changed: bool,
+    ) {
+        // The highlighted chip stays armed until the gap is typed in or left.
+        let typed = self
+            .focused
+            .and_then(|f| outputs.get(f))
+            .is_some_and(|o| o.response.changed());
+        self.backspace_armed = state.arm.or(self
+            .backspace_armed
+            .filter(|_| !structure_changed && !state.focus_changed && !typed));
+        self.selection.chip_text_selected = !structure_changed
+            && self
+                .focused
+                .filter(|f| f % 2 == 1)
+                .and_then(|f| Some((outputs.get(f)?, texts.get(f / 2)?)))
+                .is_some_and(|(output, text)| {
+                    output.cursor_range.as_ref().is_none_or(|cursor| {
+                        cursor.as_sorted_char_range() == (0..text.chars().count())
+                    })
+                });
+        if let Some(output) = self.focused.and_then(|f| outputs.get(f)) {
+            self.hold_tab(ui, texts, output.response.id);
+        }
+    }
+
+    /// Returns whether the check set with
+    /// [`ChipEditBuilder::invalid_chip_if`](crate::ChipEditBuilder::invalid_chip_if)
+    /// marks a chip with `text` invalid.
+    pub(crate) fn is_invalid_chip(&self, text: &str) -> bool {
+        self.invalid_chip_if
+            .as_ref()
+            .is_some_and(|check| check(text))
+    }
+
+    /// Returns how many more chars the chips may hold before reaching
+    /// [`ChipEditBuilder::max_total_chars`](crate::ChipEditBuilder::max_total_chars),
+    /// counting text not yet committed, or `None` if there is no budget.
     ///
     /// # Arguments
     ///
-    /// * `texts` - An iterator of strings representing the texts for the chips.
-    pub fn set_text(&mut self, texts: &mut [String]) {
-        self.rebuild(texts);
+    /// * `texts` - The texts backing the widget.
+    pub fn chars_left(&self, texts: &[String]) -> Option<usize> {
+        let used: usize = texts
+            .iter()
+            .chain(&self.separator_text)
+            .map(|text| text.chars().count())
+            .sum();
+        self.max_total_chars
+            .map(|max_total_chars| max_total_chars.saturating_sub(used))
     }
 
-    fn split(&mut self, texts: &mut Vec<String>) {
-        let mut temp_texts = vec![];
-        for (index, unit) in self.units.iter().enumerate() {
-            // skip empty separators. we still care about non empty separators
-            if unit.is_separator() && self.separator_text[index / 2].is_empty() {
-                continue;
-            }
+    /// Returns the frame drawn around the chips.
+    fn frame(&self, ui: &Ui, widget_bg: Color32, invalid: bool) -> egui::Frame {
+        egui::Frame::new()
+            .fill(widget_bg)
+            .stroke(self.default_fg_stroke(ui, invalid))
+            .shadow(self.style.widget_shadow.unwrap_or(Shadow::NONE))
+            .corner_radius(self.style.corner_radius.unwrap_or_default())
+            .inner_margin(self.style.widget_padding)
+    }
+
+    /// Shows `texts` as read-only chips in the frame of the widget, without
+    /// editors or focus handling, and without a `Vec<String>` backing them,
+    /// e.g. for a slice of `&str` or the keys of a map. Chips are styled like
+    /// the widget, including its color and icon hooks, but not the per-chip
+    /// styling of its own chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `ui` - The UI to display the chips in.
+    /// * `texts` - The texts of the chips.
+    ///
+    /// # Returns
+    ///
+    /// The response of the whole widget.
+    pub fn show_iter<'a>(&self, ui: &mut Ui, texts: impl Iterator<Item = &'a str>) -> Response {
+        let widget_bg = self.widget_bg.unwrap_or(Self::default_widget_bg_color(ui));
+        let layout = Layout::from_main_dir_and_cross_align(Direction::LeftToRight, Align::TOP)
+            .with_main_wrap(!self.single_row)
+            .with_cross_justify(false);
+        let size = self.widget_size(ui, self.min_size);
+        ui.allocate_ui(size, |ui| {
+            ui.with_layout(layout, |ui| {
+                ui.spacing_mut().item_spacing = self.style.chip_spacing;
+                self.frame(ui, widget_bg, self.invalid).show(ui, |ui| {
+                    if let Some(min_size) = self.min_size {
+                        ui.set_min_size(min_size);
+                    }
+                    for (index, text) in texts.enumerate() {
+                        let mut chip = self.new_chip(text);
+                        if let Some(chip_color_fn) = &self.chip_color_fn {
+                            let (bg_color, text_color) = chip_color_fn(text, index);
+                            chip.style.bg_color = Some(bg_color);
+                            chip.style.text_color = Some(text_color);
+                        }
+                        if let Some(icon_fn) = &self.icon_fn {
+                            chip.style.icon = icon_fn(text, index);
+                        }
+                        chip.paint_static(ui, text);
+                    }
+                });
+            })
+        })
+        .response
+    }
+
+    /// Returns the minimum size of the widget, or `None` while it is shrunk to
+    /// a single row because it is empty and unfocused.
+    fn min_size(&self, texts: &[String]) -> Option<Vec2> {
+        let shrunk = self.shrink_when_empty
+            && texts.is_empty()
+            && self.focused.is_none()
+            && !self.entry_focused
+            && self.separator_text.iter().all(String::is_empty);
+        self.min_size.filter(|_| !shrunk)
+    }
+
+    /// Returns the size offered to the widget: the desired or available width
+    /// and the height of one row, clamped to `min_size` and the max size.
+    fn widget_size(&self, ui: &Ui, min_size: Option<Vec2>) -> Vec2 {
+        let mut width = self
+            .desired_width
+            .unwrap_or(ui.available_size_before_wrap().x);
+        if self.in_window {
+            // Never widen an auto-sized window beyond its inner width.
+            width = width.min(ui.available_width());
+        }
+        let mut size = vec2(width, 20.);
+        if let Some(min_size) = min_size {
+            size = size.max(min_size);
+        }
+        if let Some(max_size) = self.max_size {
+            size = size.min(max_size);
+        }
+        size
+    }
+
+    /// Returns the height beyond which wrapped chips scroll vertically, if
+    /// any.
+    fn max_height(&self, ui: &Ui) -> Option<f32> {
+        let max_rows = self
+            .max_rows
+            .map(|max_rows| max_rows as f32 * self.row_height(ui));
+        let max_size = self.max_size.map(|max_size| max_size.y);
+        max_rows.into_iter().chain(max_size).reduce(f32::min)
+    }
+
+    /// Returns the height of all units as laid out during the last `show`.
+    fn content_height(&self) -> f32 {
+        self.unit_rects
+            .iter()
+            .map(|rect| rect.bottom())
+            .fold(0., f32::max)
+    }
+
+    /// Paints the chips without editors, while they are edited elsewhere.
+    fn paint_static_units(&self, ui: &mut Ui, texts: &[String]) {
+        let chips = self.units.iter().skip(1).step_by(2);
+        for (chip, text) in chips.zip(texts) {
+            chip.paint_static(ui, text);
+        }
+    }
 
-            let (text, clear) = if let ChipKind::Separator = &unit.kind {
-                (&mut self.separator_text[index / 2], true)
+    /// Paints the example chips as outlined chips in the weak text color.
+    fn paint_examples(&self, ui: &mut Ui) {
+        let style = self
+            .chip_style
+            .clone()
+            .colors(ui.visuals().weak_text_color(), Color32::TRANSPARENT)
+            .variant(ChipVariant::Outlined)
+            .icon(None)
+            .trailing_icon(None);
+        let chip = Chip::new_chip(style);
+        for example in &self.examples {
+            chip.paint_static(ui, example);
+        }
+    }
+
+    /// Returns the height of a row of wrapped chips, including the spacing
+    /// between rows, as laid out during the last `show`.
+    pub(crate) fn row_height(&self, ui: &Ui) -> f32 {
+        let min_height = 20.;
+        self.unit_rects
+            .iter()
+            .map(|rect| rect.height())
+            .fold(min_height, f32::max)
+            + ui.spacing().item_spacing.y
+    }
+
+    /// Makes the widget itself reachable with Tab from outside, ahead of its
+    /// editors. While it has focus, Enter enters the trailing editor and typed
+    /// text starts a new chip, rewritten like text typed in an editor.
+    fn focus_entry(&mut self, ui: &mut Ui, texts: &mut Vec<String>) {
+        let id = self.id.unwrap_or_else(|| ui.next_auto_id()).with("entry");
+        let response = ui.interact(self.entry_rect, id, Sense::focusable_noninteractive());
+        self.entry_focused = response.has_focus();
+        if !self.entry_focused {
+            return;
+        }
+        self.rewrite_input(ui);
+        let (enter, typed) = ui.input_mut(|i| {
+            let enter = i.consume_key(Modifiers::NONE, Key::Enter);
+            let mut typed = String::new();
+            i.events.retain(|event| match event {
+                Event::Text(text) => {
+                    typed.push_str(text);
+                    false
+                }
+                _ => true,
+            });
+            (enter, typed)
+        });
+        if !typed.is_empty() && self.fixed_vocabulary {
+            // Typed text only searches the vocabulary.
+            self.separator_text[texts.len()].push_str(&typed);
+            self.focus_chip(texts.len());
+        } else if !typed.is_empty() && self.chars_left(texts) != Some(0) {
+            self.push(texts, &typed);
+            let unit = texts.len() * 2 - 1;
+            self.units[unit].typing = true;
+            if holds_separator(&typed, &self.separator, self.quotes) {
+                // Like a separator typed in the new chip's editor.
+                self.split(texts, unit);
+                self.focus_chip(texts.len());
             } else {
-                (&mut texts[index / 2], false)
-            };
-            let mut v = text.split(&self.separator).map(|s| s.to_owned()).collect();
-            temp_texts.append(&mut v);
-            if clear {
-                text.clear();
+                self.focus_chip(texts.len() - 1);
             }
+        } else if enter {
+            self.focus_chip(texts.len());
+        }
+    }
+
+    /// Fades out the edges of the single row behind which chips are scrolled
+    /// out of view.
+    fn paint_fades<R>(ui: &Ui, scroll: &ScrollAreaOutput<R>, widget_bg: Color32) {
+        let rect = scroll.inner_rect;
+        let offset = scroll.state.offset.x;
+        let width = (rect.width() / 2.).min(16.);
+        // Allow half a point of rounding at either end of the scroll range.
+        if offset > 0.5 {
+            let fade = Rect::from_min_size(rect.left_top(), vec2(width, rect.height()));
+            ui.painter().add(edge_fade(fade, widget_bg, true));
+        }
+        if scroll.content_size.x - (offset + rect.width()) > 0.5 {
+            let fade = Rect::from_min_max(rect.right_top() - vec2(width, 0.), rect.right_bottom());
+            ui.painter().add(edge_fade(fade, widget_bg, false));
+        }
+    }
+
+    /// Assigns every chip the first letter or digit of its label that no
+    /// earlier chip uses.
+    fn assign_mnemonics(&mut self, texts: &[String]) {
+        let mut used = Vec::with_capacity(texts.len());
+        for (chip, text) in self.units.iter_mut().skip(1).step_by(2).zip(texts) {
+            let label = chip.label.as_deref().unwrap_or(text);
+            chip.mnemonic = label
+                .chars()
+                .map(|c| c.to_ascii_lowercase())
+                .find(|c| c.is_ascii_alphanumeric() && !used.contains(c));
+            used.extend(chip.mnemonic);
         }
-        std::mem::swap(texts, &mut temp_texts);
-        self.rebuild(texts);
     }
 
-    fn merge(&mut self, texts: &mut Vec<String>, (a, b): (usize, usize), delete: usize) {
-        let unit_min = if a < b { a } else { b };
-        let mut text_min = 0;
-        let unit_max = if a > b { a } else { b };
-        let mut temp_texts = vec![];
-        for (index, _) in self
+    /// Focuses the chip whose mnemonic is pressed with Alt, or leaves it if it
+    /// has focus already. Only acts while the widget or nothing else in the
+    /// ui has focus.
+    fn press_mnemonic(&mut self, ui: &Ui) {
+        if self.focused.is_none() && !self.entry_focused && ui.memory(|m| m.focused().is_some()) {
+            return;
+        }
+        let key = ui.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                Event::Key {
+                    key,
+                    pressed: true,
+                    modifiers,
+                    ..
+                } if modifiers.alt => Some(*key),
+                _ => None,
+            })
+        });
+        let Some((key, pressed)) = key.and_then(|key| Some((key, key_char(key)?))) else {
+            return;
+        };
+        let Some(index) = self
             .units
             .iter()
-            .enumerate()
-            .filter(|(i, u)| *i != delete && !u.is_separator())
-        {
-            if index == unit_min {
-                text_min = temp_texts.len();
-            }
-            if index != unit_max {
-                temp_texts.push(texts[index / 2].to_owned());
-            } else {
-                temp_texts
-                    .get_mut(text_min)
-                    .unwrap()
-                    .push_str(&texts[index / 2]);
-            }
+            .skip(1)
+            .step_by(2)
+            .position(|chip| chip.mnemonic == Some(pressed))
+        else {
+            return;
+        };
+        ui.input_mut(|i| i.consume_key(Modifiers::ALT, key));
+        if self.focused == Some(index * 2 + 1) {
+            self.surrender_focus();
+        } else {
+            self.focus_chip(index);
         }
-        std::mem::swap(texts, &mut temp_texts);
-        self.rebuild(texts);
     }
 
-    /// Displays the `UnownedChipEdit` widget in the given UI and returns the
-    /// response.
-    ///
-    /// # Arguments
-    ///
-    /// * `ui` - The UI to display the widget in.
-    /// * `texts` - A mutable reference to a vector of strings representing the
-    ///   texts for the chips.
+    /// Returns the indices of the chips that were visible during the last
+    /// `show`, up to one per number key.
+    fn visible_chips(&self, ui: &Ui) -> Vec<usize> {
+        self.chip_rects
+            .iter()
+            .enumerate()
+            .filter(|(_, rect)| ui.is_rect_visible(**rect))
+            .map(|(index, _)| index)
+            .take(9)
+            .collect()
+    }
+
+    /// Swaps the focused chip with its neighbor when a move shortcut is
+    /// pressed.
     ///
-    /// # Returns
+    /// Returns `true` if the chip moved.
+    fn move_focused_chip(&mut self, ui: &Ui, texts: &mut [String]) -> bool {
+        let Some((left, right)) = self.move_shortcuts else {
+            return false;
+        };
+        // Sorted chips would move straight back.
+        let Some(unit) = self.focused.filter(|f| {
+            f % 2 == 1 && self.sort.is_none() && !self.chip_locked(f / 2, &texts[f / 2])
+        }) else {
+            return false;
+        };
+        let index = unit / 2;
+        let (left, right) =
+            ui.input_mut(|i| (i.consume_shortcut(&left), i.consume_shortcut(&right)));
+        let to = if left {
+            index.checked_sub(1)
+        } else if right {
+            Some(index + 1).filter(|to| *to < texts.len())
+        } else {
+            None
+        };
+        // A locked chip is not pushed aside either.
+        let Some(to) = to.filter(|to| !self.chip_locked(*to, &texts[*to])) else {
+            return false;
+        };
+        self.swap(texts, index, to);
+        true
+    }
+
+    /// Toggles quick-delete mode with its shortcut and, while it is active,
+    /// deletes the visible chip whose number was pressed. Escape leaves the
+    /// mode.
     ///
-    /// A `Response` object representing the result of the widget interaction.
-    pub fn ui(&mut self, ui: &mut Ui, texts: &mut Vec<String>) -> Response {
-        self.show(ui, texts).response
+    /// Returns `true` if a chip was deleted.
+    fn quick_delete(&mut self, ui: &Ui, texts: &mut Vec<String>) -> bool {
+        let Some(shortcut) = self.quick_delete_shortcut else {
+            return false;
+        };
+        if self.focused.is_none() && !self.entry_focused {
+            self.quick_deleting = false;
+            return false;
+        }
+        if ui.input_mut(|i| i.consume_shortcut(&shortcut)) {
+            self.quick_deleting = !self.quick_deleting;
+        }
+        if !self.quick_deleting {
+            return false;
+        }
+        let mut number = None;
+        ui.input_mut(|i| {
+            i.events.retain(|event| match event {
+                Event::Key {
+                    key: Key::Escape,
+                    pressed: true,
+                    ..
+                } => {
+                    self.quick_deleting = false;
+                    false
+                }
+                Event::Key {
+                    key, pressed: true, ..
+                } if digit(*key).is_some() => {
+                    number = digit(*key);
+                    false
+                }
+                // Keep the number keys from also being typed into the editor.
+                Event::Text(text) => !text.chars().all(|c| c.is_ascii_digit()),
+                _ => true,
+            })
+        });
+        let Some(index) = number
+            .filter(|number| *number > 0)
+            .and_then(|number| self.visible_chips(ui).get(number - 1).copied())
+            .filter(|index| !self.units[index * 2 + 1].read_only)
+        else {
+            return false;
+        };
+        self.remove(texts, index);
+        if texts.is_empty() {
+            self.quick_deleting = false;
+        }
+        true
     }
 
-    /// Returns the default background color for the widget
-    pub fn default_widget_bg_color(ui: &Ui) -> Color32 {
-        ui.visuals().extreme_bg_color
+    /// Labels the visible chips with the number key that deletes them.
+    fn paint_quick_delete_badges(&self, ui: &Ui) {
+        let radius = 6.;
+        let font = FontId::proportional(radius * 1.5);
+        let visuals = ui.visuals();
+        for (number, index) in self.visible_chips(ui).into_iter().enumerate() {
+            let center = self.chip_rects[index].left_top() + vec2(radius, radius);
+            ui.painter()
+                .circle_filled(center, radius, visuals.warn_fg_color);
+            ui.painter().text(
+                center,
+                Align2::CENTER_CENTER,
+                number + 1,
+                font.clone(),
+                visuals.extreme_bg_color,
+            );
+        }
     }
 
-    /// Returns the default foreground color for the widget
-    pub fn default_widget_fg_color(ui: &Ui) -> Color32 {
-        ui.visuals().selection.stroke.color
+    /// Shows every unit and collects the focus action of the frame in `state`.
+    fn show_units(
+        &mut self,
+        ui: &mut Ui,
+        texts: &mut [String],
+        state: &mut State,
+        outputs: &mut Vec<ChipEditOutput>,
+    ) {
+        let max_index = self.units.len() - 1;
+        // A single row has no end of row to grow to.
+        let grow_trailing_gap = self.grow_trailing_gap && !self.single_row;
+        // Rects are kept relative to the start of the units, which moves when
+        // an enclosing scroll area scrolls.
+        let origin = ui.cursor().min.to_vec2();
+        let reuse_rects = self.virtualized && self.unit_rects.len() == self.units.len();
+        let mut unit_rects = Vec::with_capacity(self.units.len());
+        let mut selection_click = None;
+        let chars_left = self.chars_left(texts);
+        let collapsed = self.collapsed(texts.len());
+        // A collapsed widget shows the first chips and the gaps before them.
+        let shown = collapsed.map_or(self.units.len(), |count| count * 2);
+        for (index, unit) in self.units.iter_mut().enumerate().take(shown) {
+            let hidden = reuse_rects
+                .then(|| self.unit_rects[index].translate(origin))
+                .filter(|rect| !ui.is_rect_visible(*rect));
+            if let Some(rect) = hidden
+                && self.focused != Some(index)
+                && state.focus != Some(index)
+            {
+                // Reserve the space of the last frame instead of laying out the
+                // unit.
+                let (rect, response) = ui.allocate_exact_size(rect.size(), Sense::hover());
+                if !unit.is_separator() {
+                    self.chip_rects.push(rect);
+                }
+                unit_rects.push(rect.translate(-origin));
+                outputs.push(response.into());
+                continue;
+            }
+
+            let text = if let ChipKind::Separator = &mut unit.kind {
+                self.separator_text.get_mut(index / 2).unwrap()
+            } else {
+                texts.get_mut(index / 2).unwrap()
+            };
+            if let Some(chip_color_fn) = &self.chip_color_fn
+                && !unit.is_separator()
+            {
+                let (bg_color, text_color) = chip_color_fn(text, index / 2);
+                unit.style.bg_color = Some(bg_color);
+                unit.style.text_color = Some(text_color);
+            }
+            if let Some(icon_fn) = &self.icon_fn
+                && !unit.is_separator()
+            {
+                unit.style.icon = icon_fn(text, index / 2);
+            }
+            if let Some(image_fn) = &self.image_fn
+                && !unit.is_separator()
+            {
+                unit.image = image_fn(text, index / 2);
+            }
+            unit.grow = grow_trailing_gap && index == max_index;
+            // Room for one more separator lets a full gap still be committed.
+            let separator_len = if unit.is_separator() {
+                self.separator.chars().count()
+            } else {
+                0
+            };
+            unit.char_limit =
+                chars_left.map(|chars_left| text.chars().count() + chars_left + separator_len);
+            let unit_id = self.id.map(|id| id.with(index));
+            if !unit.is_separator() {
+                unit.read_only = unit.locked
+                    || self
+                        .locked_fn
+                        .as_ref()
+                        .is_some_and(|locked_fn| locked_fn(index / 2, text));
+            }
+            if !unit.is_separator() {
+                unit.invalid = self
+                    .invalid_chip_if
+                    .as_ref()
+                    .is_some_and(|check| check(text));
+                unit.content_ui = self
+                    .chip_ui_fn
+                    .as_ref()
+                    .map(|chip_ui_fn| (chip_ui_fn.clone(), index / 2));
+                // A chip being edited only becomes a date chip once the edit
+                // is finished, so that typing can go on past a complete date.
+                if self.focused != Some(index) || unit.date_picker {
+                    unit.date_picker = self.date_picker && split_date(text).is_some();
+                }
+                // Likewise for color chips, so that `#rrggbb` can be typed on
+                // to `#rrggbbaa`.
+                if self.focused != Some(index) || unit.swatch.is_some() {
+                    unit.swatch = split_color(text)
+                        .filter(|_| self.color_picker)
+                        .map(|(_, color)| color);
+                }
+                unit.link = self.open_links && link_url(text).is_some();
+                unit.armed = self.backspace_armed == Some(index);
+            }
+            // Chips from a fixed vocabulary, locked chips, chips drawn by the
+            // app, date chips and color chips are never edited.
+            let editing = self.focused == Some(index)
+                && !self.fixed_vocabulary
+                && !unit.read_only
+                && unit.content_ui.is_none()
+                && !unit.date_picker
+                && unit.swatch.is_none();
+            let renderer = self.renderer.as_deref().unwrap_or(&DefaultChipRenderer);
+            // Only the focused chip and the popups of pickers change text.
+            let before = (!unit.is_separator()
+                && (self.focused == Some(index) || unit.date_picker || unit.swatch.is_some()))
+            .then(|| text.clone());
+            let mut output = unit.show(renderer, ui, unit_id, editing, text);
+            if unit.date_picker && !unit.read_only {
+                pick_date(ui, &mut output, text);
+            } else if unit.swatch.is_some() && !unit.read_only {
+                pick_color(ui, &mut output, text);
+            }
+            if let Some(before) = before {
+                self.events.extend(ChipEvent::edit(index / 2, before, text));
+            }
+            if unit.icon_clicked {
+                output.leading_icon_clicked = Some(index / 2);
+            }
+            if unit.trailing_icon_clicked {
+                output.trailing_icon_clicked = Some(index / 2);
+            }
+            if self.selection.enabled && !unit.is_separator() && output.response.clicked() {
+                selection_click = Some(index);
+            }
+            if unit.link
+                && ((output.response.clicked() && ui.input(|i| i.modifiers.command))
+                    || unit.trailing_icon_clicked)
+                && let Some(url) = link_url(text)
+            {
+                ui.ctx().open_url(OpenUrl::new_tab(url));
+            }
+            if let Some(hover_ui) = &self.hover_ui
+                && !unit.is_separator()
+            {
+                output.response = output
+                    .response
+                    .on_hover_ui(|ui| hover_ui(ui, index / 2, text));
+            }
+            if let Some(tooltip_fn) = &self.tooltip_fn
+                && !unit.is_separator()
+                && output.response.hovered()
+                && let Some(tooltip) = tooltip_fn(text, index / 2)
+            {
+                output.response = output.response.on_hover_text(tooltip);
+            }
+            if let Some(context_menu_fn) = &self.context_menu_fn
+                && !unit.is_separator()
+            {
+                output
+                    .response
+                    .context_menu(|ui| context_menu_fn(ui, index / 2, text));
+            }
+            // Enter ends the edit of a chip edited as text, and opens the
+            // picker of a date or color chip.
+            if let Some(activate_fn) = &self.activate_fn
+                && !unit.is_separator()
+                && !editing
+                && !unit.date_picker
+                && unit.swatch.is_none()
+                && output.response.has_focus()
+                && ui.input(|i| i.key_pressed(Key::Enter))
+            {
+                activate_fn(index / 2, text);
+            }
+            state.update(max_index, index, unit, &output, &self.separator, text);
+            if state.focus == Some(index) && !output.response.has_focus() {
+                output.response.request_focus();
+                // egui does not report a requested focus as gained, so the
+                // text to restore on Escape is kept now.
+                if !unit.is_separator() {
+                    state.snapshot = Some((index, text.clone()));
+                }
+            } else if self.pending_surrender && output.response.has_focus() {
+                output.response.surrender_focus();
+            }
+
+            if (self.single_row || self.max_rows.is_some() || self.max_size.is_some())
+                && state.focus == Some(index)
+                && (output.gained_focus() || output.response.changed())
+            {
+                // Keep the focused chip in view while moving through or
+                // typing in chips that do not fit the widget.
+                ui.scroll_to_rect(output.response.rect, None);
+            }
+
+            unit.update_position(&output, text);
+            if !unit.is_separator() {
+                self.chip_rects.push(output.response.rect);
+            }
+            unit_rects.push(output.response.rect.translate(-origin));
+            outputs.push(output);
+        }
+        if let Some(unit) = selection_click {
+            let modifiers = ui.input(|i| i.modifiers);
+            self.select_clicked(unit, modifiers);
+        }
+        if texts.is_empty() {
+            self.paint_examples(ui);
+        }
+        if let Some(count) = collapsed
+            && self.show_more(ui, texts.len() - count).clicked()
+        {
+            self.expanded = true;
+        }
+        self.unit_rects = unit_rects;
+    }
+
+    /// Returns how many chips a collapsed widget shows, or `None` if it shows
+    /// all of them.
+    fn collapsed(&self, len: usize) -> Option<usize> {
+        self.collapse_after
+            .filter(|count| len > *count && self.focused.is_none() && !self.expanded)
+    }
+
+    /// Shows the chip standing in for the `hidden` chips of a collapsed widget.
+    fn show_more(&self, ui: &mut Ui, hidden: usize) -> Response {
+        let mut label = format!("+{hidden}");
+        let renderer = self.renderer.as_deref().unwrap_or(&DefaultChipRenderer);
+        self.new_chip(&label)
+            .show(renderer, ui, None, false, &mut label)
+            .response
+            .on_hover_text("Show all")
+    }
+
+    /// Replaces the paste separators in pasted text and the typed separators
+    /// in typed text with the separator, so that the regular splitting turns
+    /// every line, cell or entry into a chip, then drops the characters the
+    /// char filter rejects.
+    fn rewrite_input(&self, ui: &Ui) {
+        if self.paste_separators.is_empty()
+            && self.typed_separators.is_empty()
+            && self.char_filter.is_none()
+        {
+            return;
+        }
+        let separators = self.paste_separators.as_slice();
+        let typed = self.typed_separators.as_slice();
+        ui.input_mut(|i| {
+            for event in &mut i.events {
+                match event {
+                    Event::Paste(text) if !separators.is_empty() => {
+                        *text = text
+                            .replace("\r\n", "\n")
+                            .trim_end_matches(separators)
+                            .split(separators)
+                            .collect::<Vec<_>>()
+                            .join(&self.separator);
+                    }
+                    Event::Text(text) if !typed.is_empty() => {
+                        *text = text.replace(typed, &self.separator);
+                    }
+                    _ => {}
+                }
+                if let Some(char_filter) = &self.char_filter
+                    && let Event::Text(text) | Event::Paste(text) = event
+                {
+                    // The separator is kept so that chips can still be split.
+                    text.retain(|c| char_filter(c) || self.separator.contains(c));
+                }
+            }
+            // Keystrokes reduced to nothing are not typed at all.
+            i.events.retain(
+                |event| !matches!(event, Event::Text(text) | Event::Paste(text) if text.is_empty()),
+            );
+        });
+    }
+
+    /// Highlights the frame and the insertion point while a `String` payload
+    /// is dragged over the widget, and inserts it as a chip there on release.
+    ///
+    /// Returns `true` if a chip was dropped.
+    fn accept_drop(&mut self, ui: &Ui, response: &Response, texts: &mut Vec<String>) -> bool {
+        // A widget does not count as hovered while something is dragged over
+        // it, so the pointer is read from the input instead.
+        let Some(pointer) = response
+            .dnd_hover_payload::<String>()
+            .filter(|_| response.contains_pointer())
+            .and_then(|_| ui.input(|i| i.pointer.interact_pos()))
+        else {
+            return false;
+        };
+        let color = self
+            .style
+            .drop_highlight
+            .unwrap_or(ui.visuals().selection.stroke.color);
+        let stroke = Stroke::new(2., color);
+        ui.painter().rect_stroke(
+            response.rect,
+            self.style.corner_radius.unwrap_or_default(),
+            stroke,
+            StrokeKind::Inside,
+        );
+        let index = DropTarget::index(&self.chip_rects, pointer);
+        if let Some((x, y_range)) = DropTarget::marker(&self.chip_rects, index) {
+            ui.painter().vline(x, y_range, stroke);
+        }
+        let Some(text) = response.dnd_release_payload::<String>() else {
+            return false;
+        };
+        self.insert(texts, index, text.as_str());
+        true
+    }
+
+    /// Shows the "Import" link and, while it is open, the window whose text
+    /// is appended as chips on confirmation.
+    ///
+    /// Returns `true` if chips were imported.
+    fn show_import(&mut self, ui: &mut Ui, id: Id, texts: &mut Vec<String>) -> bool {
+        if !self.import.is_open() && ui.link("Import…").clicked() {
+            self.import.open();
+        }
+        let Some(mut text) = self.import.text.take() else {
+            return false;
+        };
+        let mut open = true;
+        let (mut confirmed, mut cancelled) = (false, false);
+        egui::Window::new("Import chips")
+            .id(id)
+            .open(&mut open)
+            .collapsible(false)
+            .show(ui.ctx(), |ui| {
+                ui.add(
+                    TextEdit::multiline(&mut text)
+                        .hint_text("Paste one chip per line")
+                        .desired_width(f32::INFINITY),
+                );
+                let count = self.tokenize(&text).len();
+                ui.label(match count {
+                    1 => "1 chip will be created".to_owned(),
+                    count => format!("{count} chips will be created"),
+                });
+                ui.horizontal(|ui| {
+                    confirmed = ui.add_enabled(count > 0, Button::new("Import")).clicked();
+                    cancelled = ui.button("Cancel").clicked();
+                });
+            });
+        if confirmed {
+            let chips = self.tokenize(&text);
+            self.insert_chips(texts, texts.len(), chips);
+            return true;
+        }
+        if open && !cancelled {
+            self.import.text = Some(text);
+        }
+        false
+    }
+
+    /// Returns whether chips with the texts `a` and `b` are duplicates.
+    fn same_chip(&self, a: &str, b: &str) -> bool {
+        match &self.dedup_eq {
+            Some(eq) => eq(a, b),
+            None => a == b,
+        }
+    }
+
+    /// Removes the chips that duplicate an earlier chip, keeping the first of
+    /// them. The edited chip is left alone until the focus leaves it.
+    ///
+    /// Returns `true` if any chip was removed.
+    fn remove_duplicates(&mut self, texts: &mut Vec<String>) -> bool {
+        let mut removed = false;
+        let mut index = 0;
+        while index < texts.len() {
+            // Removing a chip moves the focus with the chips after it.
+            let editing = self.focused.filter(|f| f % 2 == 1).map(|f| f / 2);
+            let duplicate = Some(index) != editing
+                && (0..index)
+                    .filter(|earlier| Some(*earlier) != editing)
+                    .any(|earlier| self.same_chip(&texts[earlier], &texts[index]));
+            if duplicate && !self.units[index * 2 + 1].read_only {
+                self.remove(texts, index);
+                removed = true;
+            } else {
+                index += 1;
+            }
+        }
+        removed
+    }
+
+    /// Reorders the chips by `sort`, keeping the text of the gaps in place. The
+    /// focus follows the focused chip.
+    ///
+    /// Returns `true` if any chip moved.
+    fn sort_chips(&mut self, texts: &mut [String], sort: ChipSort) -> bool {
+        let mut order: Vec<usize> = (0..texts.len()).collect();
+        // Stable, so that equal chips keep their order.
+        order.sort_by(|a, b| sort.compare(&texts[*a], &texts[*b]));
+        if order.iter().enumerate().all(|(to, from)| to == *from) {
+            return false;
+        }
+        self.events.extend(ChipEvent::moves(&order));
+        self.restructured = true;
+        let mut chips: Vec<Option<(String, Chip)>> = Vec::with_capacity(texts.len());
+        let mut gaps = Vec::with_capacity(texts.len() + 1);
+        for (unit, chip) in std::mem::take(&mut self.units).into_iter().enumerate() {
+            if unit % 2 == 0 {
+                gaps.push(chip);
+            } else {
+                chips.push(Some((std::mem::take(&mut texts[unit / 2]), chip)));
+            }
+        }
+        let mut gaps = gaps.into_iter();
+        self.units.extend(gaps.next());
+        for (to, from) in order.iter().enumerate() {
+            let (text, chip) = chips[*from].take().expect("every chip is moved once");
+            texts[to] = text;
+            self.units.push(chip);
+            self.units.extend(gaps.next());
+        }
+        if let Some(focused) = self.focused.as_mut()
+            && *focused % 2 == 1
+            && let Some(to) = order.iter().position(|from| *from == *focused / 2)
+        {
+            *focused = to * 2 + 1;
+        }
+        true
+    }
+
+    /// Splits pasted `text` into chips like a paste into a gap, dropping empty
+    /// pieces.
+    fn tokenize(&self, text: &str) -> Vec<CommittedChip> {
+        let text = text
+            .replace("\r\n", "\n")
+            .split(self.paste_separators.as_slice())
+            .collect::<Vec<_>>()
+            .join(&self.separator);
+        let mut chips = self.commit(&text);
+        chips.retain(|chip| !chip.text.trim().is_empty());
+        chips
+    }
+
+    /// Opens, shows and applies the suggestion popup.
+    ///
+    /// Returns `true` if a suggestion was accepted, which changes the chip
+    /// indices.
+    fn update_suggestions(
+        &mut self,
+        ui: &Ui,
+        popup_id: Id,
+        outputs: &[ChipEditOutput],
+        texts: &mut Vec<String>,
+    ) -> bool {
+        if let Some(focus) = self.focused.filter(|f| *f < outputs.len()) {
+            let unit = &self.units[focus];
+            let empty_gap = unit.is_separator() && self.separator_text[focus / 2].is_empty();
+            let shortcut = self.suggestions.shortcut;
+            let explicit = ui.input_mut(|i| i.consume_shortcut(&shortcut))
+                || (empty_gap
+                    && self.suggestions.anchor.is_none()
+                    && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowDown)));
+            // Mentions have their own popup.
+            let mention = !unit.is_separator() && self.mentions.query(&texts[focus / 2]).is_some();
+            let searched = (!unit.is_separator() || self.fixed_vocabulary) && !mention;
+            if explicit || (searched && outputs[focus].response.changed()) {
+                self.suggestions.open(focus);
+                ui.memory_mut(|m| m.open_popup(popup_id));
+            }
+        }
+
+        let Some(anchor) = self.suggestions.anchor.filter(|a| *a < outputs.len()) else {
+            self.suggestions.close();
+            return false;
+        };
+        if !ui.memory(|m| m.is_popup_open(popup_id)) {
+            self.suggestions.close();
+            return false;
+        }
+
+        let editing = (anchor % 2 == 1).then_some(anchor / 2);
+        let query = match editing {
+            Some(index) => texts[index].as_str(),
+            None if self.fixed_vocabulary => self.separator_text[anchor / 2].as_str(),
+            None => "",
+        };
+        let exclude: Vec<String> = texts
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| Some(*index) != editing)
+            .map(|(_, text)| text.clone())
+            .collect();
+        let matches = self.suggestions.matches(query, |item| {
+            exclude.iter().any(|text| self.same_chip(text, item))
+        });
+        if matches.is_empty() {
+            self.suggestions.close();
+            ui.memory_mut(|m| m.close_popup());
+            return false;
+        }
+
+        let Some(picked) = self
+            .suggestions
+            .show(ui, popup_id, &outputs[anchor].response, &matches)
+            .map(|index| matches[index].clone())
+        else {
+            return false;
+        };
+        self.suggestions.remember(&picked);
+        let mut next = anchor + 1;
+        if let Some(index) = editing {
+            // The picked value is taken as is, even for a chip being typed.
+            self.units[anchor].typing = false;
+            let picked = match self.chars_left(texts) {
+                Some(left) => picked
+                    .chars()
+                    .take(left + texts[index].chars().count())
+                    .collect(),
+                None => picked,
+            };
+            let old = std::mem::replace(&mut texts[index], picked);
+            self.events
+                .extend(ChipEvent::edit(index, old, &texts[index]));
+        } else {
+            self.separator_text[anchor / 2].clear();
+            let len = texts.len();
+            self.insert(texts, anchor / 2, picked);
+            next = anchor + (texts.len() - len) * 2;
+        }
+        // Continue typing in the gap after the accepted chip.
+        self.focused = Some(next);
+        true
+    }
+
+    /// Opens the mention popup while the focused chip starts with the mention
+    /// trigger and turns the chip into a mention of the picked person.
+    ///
+    /// Returns `true` if a person was picked.
+    fn update_mentions(
+        &mut self,
+        ui: &Ui,
+        popup_id: Id,
+        outputs: &[ChipEditOutput],
+        texts: &mut [String],
+    ) -> bool {
+        if let Some(focus) = self.focused.filter(|f| *f < outputs.len() && f % 2 == 1)
+            && outputs[focus].response.changed()
+            && self.mentions.query(&texts[focus / 2]).is_some()
+        {
+            self.mentions.popup.open(focus);
+            ui.memory_mut(|m| m.open_popup(popup_id));
+        }
+
+        let Some(anchor) = self
+            .mentions
+            .popup
+            .anchor
+            .filter(|a| *a < outputs.len() && a % 2 == 1)
+        else {
+            self.mentions.popup.close();
+            return false;
+        };
+        let matches = self
+            .mentions
+            .query(&texts[anchor / 2])
+            .map(|query| self.mentions.matches(query))
+            .unwrap_or_default();
+        if !ui.memory(|m| m.is_popup_open(popup_id)) || matches.is_empty() {
+            self.mentions.popup.close();
+            ui.memory_mut(|m| {
+                if m.is_popup_open(popup_id) {
+                    m.close_popup();
+                }
+            });
+            return false;
+        }
+
+        let names: Vec<String> = matches
+            .iter()
+            .map(|index| self.mentions.people[*index].name.clone())
+            .collect();
+        let Some(picked) =
+            self.mentions
+                .popup
+                .show(ui, popup_id, &outputs[anchor].response, &names)
+        else {
+            return false;
+        };
+        let person = self.mentions.people[matches[picked]].clone();
+        let old = std::mem::replace(&mut texts[anchor / 2], self.mentions.text(&person));
+        self.events
+            .extend(ChipEvent::edit(anchor / 2, old, &texts[anchor / 2]));
+        self.units[anchor].metadata = Some(person.id);
+        self.units[anchor].typing = false;
+        // Continue typing in the gap after the mention.
+        self.focused = Some(anchor + 1);
+        true
+    }
+
+    /// Returns the people mentioned by the chips, see
+    /// [`ChipEditBuilder::mentions`](crate::ChipEditBuilder::mentions).
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts backing the widget.
+    pub fn mentions(&self, texts: &[String]) -> Vec<Mention> {
+        texts
+            .iter()
+            .enumerate()
+            .filter_map(|(index, text)| {
+                let name = self.mentions.query(text)?;
+                let id = self.metadata(index)?;
+                Some(Mention::new(id, name))
+            })
+            .collect()
+    }
+
+    /// Sets the people offered in a popup while typing a mention.
+    ///
+    /// # Arguments
+    ///
+    /// * `people` - The people that can be mentioned.
+    pub fn set_mentions(&mut self, people: impl IntoIterator<Item = Mention>) {
+        self.mentions.people = people.into_iter().collect();
+        self.mentions.popup.close();
+    }
+
+    /// Rebuilds the `UnownedChipEdit` widget with the given texts.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - A vector of strings representing the texts for the chips.
+    pub(crate) fn rebuild(&mut self, texts: &mut [String]) {
+        self.units.clear();
+        self.separator_text.clear();
+        self.resize(texts);
+    }
+
+    /// Adds or drops units at the end until they match `texts` again, keeping
+    /// the state of the chips that remain.
+    fn resize(&mut self, texts: &[String]) {
+        let len = texts.len();
+        let units = len * 2 + 1;
+        self.units.truncate(units);
+        self.separator_text.resize(len + 1, String::new());
+        if self.units.is_empty() {
+            self.units.push(Chip::new_separator());
+        }
+        for text in &texts[self.units.len() / 2..] {
+            let chip = self.new_chip(text);
+            self.units.extend([chip, Chip::new_separator()]);
+        }
+        self.texts_len = len;
+        if let Some(focused) = self.focused.as_mut() {
+            *focused = (*focused).min(units - 1);
+        }
+    }
+
+    /// Creates a chip for `text` with the widget-wide chip configuration.
+    fn new_chip(&self, text: &str) -> Chip {
+        let mut chip = Chip::new_chip(self.chip_style.clone());
+        if self.range_chips && range_bounds(text).is_some() {
+            chip.kind = ChipKind::Range;
+        }
+        chip
+    }
+
+    /// Sets the texts for the chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - An iterator of strings representing the texts for the chips.
+    pub fn set_text(&mut self, texts: &mut [String]) {
+        self.rebuild(texts);
+    }
+
+    /// Resizes the units if `texts` changed length behind our back.
+    fn sync(&mut self, texts: &[String]) {
+        if self.texts_len != texts.len() {
+            self.resize(texts);
+            self.restructured = true;
+        }
+    }
+
+    /// Inserts chips for `new` at position `index` in `texts`, leaving the
+    /// other chips untouched. The caller adjusts the focus.
+    ///
+    /// Chips beyond [`Self::max_total_chars`] are cut short, and dropped once
+    /// the budget is used up.
+    fn insert_chips(&mut self, texts: &mut Vec<String>, index: usize, mut new: Vec<CommittedChip>) {
+        if let Some(mut left) = self.chars_left(texts) {
+            new.retain_mut(|committed| {
+                if left == 0 && !committed.text.is_empty() {
+                    return false;
+                }
+                committed.text = committed.text.chars().take(left).collect();
+                left -= committed.text.chars().count();
+                true
+            });
+        }
+        // The new chips and the gaps following them go right after gap `index`.
+        let unit = index * 2 + 1;
+        let chips: Vec<Chip> = new
+            .iter()
+            .flat_map(|committed| {
+                let mut chip = self.new_chip(&committed.text);
+                chip.label = committed.label.clone();
+                chip.metadata = committed.metadata.clone();
+                [chip, Chip::new_separator()]
+            })
+            .collect();
+        self.units.splice(unit..unit, chips);
+        self.separator_text
+            .splice(index + 1..index + 1, new.iter().map(|_| String::new()));
+        self.events.extend(
+            new.iter()
+                .enumerate()
+                .map(|(i, committed)| ChipEvent::Added {
+                    index: index + i,
+                    text: committed.text.clone(),
+                }),
+        );
+        texts.splice(
+            index..index,
+            new.into_iter().map(|committed| committed.text),
+        );
+        self.texts_len = texts.len();
+        self.restructured = true;
+    }
+
+    /// Turns committed `text` into chips with the commit hook, or by splitting
+    /// it on the separator.
+    fn commit(&self, text: &str) -> Vec<CommittedChip> {
+        let mut chips: Vec<CommittedChip> = match &self.commit_fn {
+            Some(commit_fn) => commit_fn(text),
+            None => text
+                .split(&self.separator)
+                .map(CommittedChip::new)
+                .collect(),
+        };
+        if let Some(normalize_fn) = &self.normalize_fn {
+            chips
+                .iter_mut()
+                .for_each(|chip| chip.text = normalize_fn(&chip.text));
+        }
+        chips
+    }
+
+    /// Turns the text just typed in the gap at `unit` into a chip, which is
+    /// committed by [`Self::finish_typing`] once it is finished.
+    fn start_typing(&mut self, texts: &mut Vec<String>, unit: usize) {
+        let text = std::mem::take(&mut self.separator_text[unit / 2]);
+        self.insert_chips(texts, unit / 2, vec![CommittedChip::new(text)]);
+        self.units[unit + 1].typing = true;
+    }
+
+    /// Commits the chip being typed, if any, like a separator typed at its
+    /// end. The focus stays on the unit it is on.
+    ///
+    /// Returns `true` if a chip was committed.
+    fn finish_typing(&mut self, texts: &mut Vec<String>) -> bool {
+        let Some(unit) = self.units.iter().position(|unit| unit.typing) else {
+            return false;
+        };
+        let len = texts.len();
+        self.split(texts, unit);
+        if let Some(focused) = self.focused.as_mut()
+            && *focused > unit
+        {
+            *focused += (texts.len() - len) * 2;
+        }
+        true
+    }
+
+    /// Commits the chip still being typed and turns any text left in the gaps
+    /// between chips into chips, so that nothing the user typed is dropped or
+    /// left unprocessed by the commit hooks, e.g. when a form is submitted
+    /// while the widget has focus.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts backing the widget.
+    ///
+    /// # Returns
+    ///
+    /// `true` if any chip was committed or added.
+    pub fn commit_all_pending(&mut self, texts: &mut Vec<String>) -> bool {
+        self.sync(texts);
+        let mut committed = self.finish_typing(texts);
+        // Back to front, so that the gaps still to visit keep their indices.
+        for gap in (0..self.separator_text.len()).rev() {
+            if self.separator_text[gap].is_empty() {
+                continue;
+            }
+            let unit = gap * 2;
+            let len = texts.len();
+            self.split(texts, unit);
+            if let Some(focused) = self.focused.as_mut()
+                && *focused > unit
+            {
+                *focused += (texts.len() - len) * 2;
+            }
+            committed = true;
+        }
+        committed
+    }
+
+    /// Appends a chip at the end of `texts`.
+    ///
+    /// The text is cut short to the chars left by
+    /// [`ChipEditBuilder::max_total_chars`](crate::ChipEditBuilder::max_total_chars),
+    /// and no chip is added once they are used up.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts backing the widget.
+    /// * `text` - The text of the new chip.
+    pub fn push(&mut self, texts: &mut Vec<String>, text: impl ToString) {
+        self.insert(texts, texts.len(), text);
+    }
+
+    /// Inserts a chip at position `index` in `texts`, shifting all chips after
+    /// it to the right.
+    ///
+    /// The text is cut short to the chars left by
+    /// [`ChipEditBuilder::max_total_chars`](crate::ChipEditBuilder::max_total_chars),
+    /// and no chip is added once they are used up.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts backing the widget.
+    /// * `index` - The position of the new chip.
+    /// * `text` - The text of the new chip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > texts.len()`.
+    pub fn insert(&mut self, texts: &mut Vec<String>, index: usize, text: impl ToString) {
+        self.sync(texts);
+        let len = texts.len();
+        self.insert_chips(texts, index, vec![CommittedChip::new(text.to_string())]);
+        let unit = index * 2 + 1;
+        if texts.len() > len
+            && let Some(focused) = self.focused.as_mut()
+            && *focused >= unit
+        {
+            *focused += 2;
+        }
+    }
+
+    /// Removes and returns the chip at position `index` in `texts`.
+    ///
+    /// Any text typed in the gap after the chip is moved to the gap before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts backing the widget.
+    /// * `index` - The position of the chip to remove.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= texts.len()`.
+    pub fn remove(&mut self, texts: &mut Vec<String>, index: usize) -> String {
+        self.sync(texts);
+        let text = texts.remove(index);
+        self.events.push(ChipEvent::Removed {
+            index,
+            text: text.clone(),
+        });
+        let unit = index * 2 + 1;
+        self.units.drain(unit..unit + 2);
+        let gap = self.separator_text.remove(index + 1);
+        self.separator_text[index].push_str(&gap);
+        self.texts_len = texts.len();
+        self.restructured = true;
+        if let Some(focused) = self.focused.as_mut() {
+            if *focused > unit + 1 {
+                *focused -= 2;
+            } else if *focused >= unit {
+                *focused = unit - 1;
+            }
+        }
+        text
+    }
+
+    /// Removes all chips from `texts`.
+    ///
+    /// If the widget had focus, the focus moves to the now empty input.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts backing the widget.
+    pub fn clear(&mut self, texts: &mut Vec<String>) {
+        self.events.extend(
+            texts
+                .drain(..)
+                .enumerate()
+                .rev()
+                .map(|(index, text)| ChipEvent::Removed { index, text }),
+        );
+        self.rebuild(texts);
+        self.restructured = true;
+        self.focused = self.focused.map(|_| 0);
+    }
+
+    /// Swaps the chips at positions `a` and `b` in `texts`.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts backing the widget.
+    /// * `a` - The position of the first chip.
+    /// * `b` - The position of the second chip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&mut self, texts: &mut [String], a: usize, b: usize) {
+        self.sync(texts);
+        texts.swap(a, b);
+        let mut order: Vec<usize> = (0..texts.len()).collect();
+        order.swap(a, b);
+        self.events.extend(ChipEvent::moves(&order));
+        let (unit_a, unit_b) = (a * 2 + 1, b * 2 + 1);
+        self.units.swap(unit_a, unit_b);
+        self.restructured = true;
+        if self.focused == Some(unit_a) {
+            self.focused = Some(unit_b);
+        } else if self.focused == Some(unit_b) {
+            self.focused = Some(unit_a);
+        }
+    }
+
+    /// Commits the text typed in the focused gap as new chips, or the focused
+    /// chip being typed, when one of the commit keys is pressed, and keeps
+    /// typing in the now empty gap after them.
+    ///
+    /// The keys are left to the popups while one is open, and to the search
+    /// of a fixed vocabulary. A commit key that types a character, e.g. comma,
+    /// never types it into the gap or the chip.
+    ///
+    /// Returns `true` if chips were added or committed.
+    fn commit_on_key(&mut self, ui: &Ui, texts: &mut Vec<String>) -> bool {
+        let Some((unit, blank)) = self.commit_target(texts) else {
+            return false;
+        };
+        let typing = unit % 2 == 1;
+        let pressed = ui.input_mut(|i| {
+            let key = self.commit_keys.iter().copied().find(|&key| {
+                (!blank || typed_char(key).is_some()) && i.consume_key(Modifiers::NONE, key)
+            })?;
+            if let Some(c) = typed_char(key) {
+                i.events
+                    .retain(|event| !matches!(event, Event::Text(text) if text.chars().eq([c])));
+            }
+            Some(key)
+        });
+        if pressed.is_none() || blank {
+            return false;
+        }
+        let len = texts.len();
+        self.split(texts, unit);
+        let gap = if typing { unit + 1 } else { unit };
+        self.focused = Some(gap + (texts.len() - len) * 2);
+        typing || texts.len() != len
+    }
+
+    /// Returns the focused unit the commit keys act on, a gap or the chip being
+    /// typed, and whether its text is blank. The keys are left to the popups
+    /// while one is open, and to the search of a fixed vocabulary.
+    fn commit_target(&self, texts: &[String]) -> Option<(usize, bool)> {
+        if self.commit_keys.is_empty()
+            || self.fixed_vocabulary
+            || self.suggestions.anchor.is_some()
+            || self.mentions.popup.anchor.is_some()
+        {
+            return None;
+        }
+        let unit = self
+            .focused
+            .filter(|f| f % 2 == 0 || self.units[*f].typing)?;
+        let text = if unit % 2 == 1 {
+            &texts[unit / 2]
+        } else {
+            &self.separator_text[unit / 2]
+        };
+        Some((unit, text.trim().is_empty()))
+    }
+
+    /// Keeps Tab in the focused editor with `id` during the next frame while
+    /// it is a commit key and there is text for it to commit, instead of
+    /// letting egui move the focus to the next widget.
+    fn hold_tab(&self, ui: &Ui, texts: &[String], id: Id) {
+        if self.commit_keys.contains(&Key::Tab)
+            && let Some((_, false)) = self.commit_target(texts)
+        {
+            let filter = EventFilter {
+                tab: true,
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                escape: false,
+            };
+            ui.memory_mut(|m| m.set_focus_lock_filter(id, filter));
+        }
+    }
+
+    /// Selects all chips on Ctrl+A in a chip whose text is all selected, and
+    /// removes the selected chips on Delete or Backspace while the keys do not
+    /// edit text: when nothing or a selected, fully selected chip has focus.
+    ///
+    /// Returns the texts of the removed chips, in order.
+    fn select_all_or_delete(&mut self, ui: &Ui, texts: &mut Vec<String>) -> Vec<String> {
+        let focused_chip = self.focused.filter(|f| f % 2 == 1);
+        if focused_chip.is_some()
+            && self.selection.chip_text_selected
+            && ui.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::A))
+        {
+            for chip in self.units.iter_mut().skip(1).step_by(2) {
+                chip.selected = true;
+            }
+            return vec![];
+        }
+        let keys_free = match focused_chip {
+            Some(unit) => self.units[unit].selected && self.selection.chip_text_selected,
+            None => {
+                self.focused.is_none()
+                    && (self.entry_focused || ui.memory(|m| m.focused().is_none()))
+            }
+        };
+        if !keys_free || self.units.iter().all(|unit| !unit.selected) {
+            return vec![];
+        }
+        let delete = ui.input_mut(|i| {
+            i.consume_key(Modifiers::NONE, Key::Delete)
+                | i.consume_key(Modifiers::NONE, Key::Backspace)
+        });
+        if delete {
+            self.remove_selected(texts)
+        } else {
+            vec![]
+        }
+    }
+
+    /// Updates the multi-selection for a click on the chip at `unit`, like a
+    /// file manager: Ctrl+click toggles the chip, Shift+click selects the
+    /// chips between the anchor and the chip, and a plain click clears the
+    /// selection. Ctrl+click and a plain click move the anchor to the chip.
+    fn select_clicked(&mut self, unit: usize, modifiers: Modifiers) {
+        let anchor = self
+            .units
+            .iter()
+            .position(|unit| unit.selection_anchor)
+            .filter(|_| modifiers.shift);
+        if let Some(anchor) = anchor {
+            let range = anchor.min(unit)..=anchor.max(unit);
+            for (index, chip) in self.units.iter_mut().enumerate().skip(1).step_by(2) {
+                chip.selected = range.contains(&index);
+            }
+            return;
+        }
+        for chip in &mut self.units {
+            chip.selection_anchor = false;
+        }
+        if modifiers.command {
+            self.units[unit].selected = !self.units[unit].selected;
+        } else if modifiers.shift {
+            // Without an anchor, Shift+click starts a selection.
+            self.clear_selection();
+            self.units[unit].selected = true;
+        } else {
+            self.clear_selection();
+        }
+        self.units[unit].selection_anchor = true;
+    }
+
+    /// Returns the positions of the chips selected with Ctrl+click, in order.
+    pub fn selected_indices(&self) -> Vec<usize> {
+        self.units
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .enumerate()
+            .filter(|(_, unit)| unit.selected)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Adds the chip at `index` to the multi-selection, or removes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    /// * `selected` - Whether the chip is selected.
+    pub fn set_selected(&mut self, index: usize, selected: bool) {
+        if let Some(unit) = self.units.get_mut(index * 2 + 1) {
+            unit.selected = selected;
+        }
+    }
+
+    /// Empties the multi-selection.
+    pub fn clear_selection(&mut self) {
+        self.units.iter_mut().for_each(|unit| unit.selected = false);
+    }
+
+    /// Removes the selected chips that are not locked from `texts`, whether
+    /// with [`Self::lock`] or by
+    /// [`ChipEditBuilder::locked_fn`](crate::ChipEditBuilder::locked_fn).
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts backing the widget.
+    ///
+    /// # Returns
+    ///
+    /// The texts of the removed chips, in order.
+    pub fn remove_selected(&mut self, texts: &mut Vec<String>) -> Vec<String> {
+        self.sync(texts);
+        let mut removed = vec![];
+        // Back to front, so that the chips still to remove keep their indices.
+        for index in self.selected_indices().into_iter().rev() {
+            if !self.chip_locked(index, &texts[index]) {
+                removed.push(self.remove(texts, index));
+            }
+        }
+        removed.reverse();
+        removed
+    }
+
+    /// Returns the screen rect of the chip at `index` as laid out during the
+    /// last `show`.
+    ///
+    /// Useful to draw anchors, connecting lines or highlights pointing at a
+    /// chip. Returns `None` if the chip was not shown.
+    pub fn chip_rect(&self, index: usize) -> Option<Rect> {
+        self.chip_rects.get(index).copied()
+    }
+
+    /// Draws the chip at `index` as `variant`, overriding
+    /// [`ChipStyle::variant`] for that chip.
+    ///
+    /// The override is lost when all texts are replaced with
+    /// [`Self::set_text`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    /// * `variant` - The variant of the chip.
+    pub fn set_variant(&mut self, index: usize, variant: ChipVariant) {
+        if let Some(chip) = self.units.get_mut(index * 2 + 1) {
+            chip.style.variant = variant;
+        }
+    }
+
+    /// Returns a read-only [`ChipSummary`] of `texts` styled like this widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts of the chips.
+    pub fn summary<'a>(&self, texts: &'a [String]) -> ChipSummary<'a> {
+        ChipSummary::styled(texts, self.new_chip(""), self.style.chip_spacing)
+    }
+
+    /// Locks the chip at `index`, so that it can neither be edited nor
+    /// deleted by the user, e.g. for mandatory tags. The app can still remove
+    /// it with [`Self::remove`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    pub fn lock(&mut self, index: usize) {
+        if let Some(chip) = self.units.get_mut(index * 2 + 1) {
+            chip.locked = true;
+        }
+    }
+
+    /// Unlocks the chip at `index` that was locked with [`Self::lock`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    pub fn unlock(&mut self, index: usize) {
+        if let Some(chip) = self.units.get_mut(index * 2 + 1) {
+            chip.locked = false;
+        }
+    }
+
+    /// Returns whether the chip at `index` with `text` is locked, with
+    /// [`Self::lock`] or by the predicate set with
+    /// [`ChipEditBuilder::locked_fn`](crate::ChipEditBuilder::locked_fn).
+    fn chip_locked(&self, index: usize, text: &str) -> bool {
+        self.units[index * 2 + 1].locked
+            || self
+                .locked_fn
+                .as_ref()
+                .is_some_and(|locked_fn| locked_fn(index, text))
+    }
+
+    /// Returns whether the chip at `index` was locked with [`Self::lock`].
+    pub fn is_locked(&self, index: usize) -> bool {
+        self.units
+            .get(index * 2 + 1)
+            .is_some_and(|chip| chip.locked)
+    }
+
+    /// Makes the widget view-only, or editable again. A view-only widget
+    /// shows the chips with the disabled visuals of egui and cannot be
+    /// edited, deleted from or focused.
+    ///
+    /// # Arguments
+    ///
+    /// * `interactive` - Whether the chips can be edited.
+    pub fn set_interactive(&mut self, interactive: bool) {
+        self.interactive = interactive;
+    }
+
+    /// Returns whether the chips can be edited.
+    pub fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    /// Draws the widget frame as invalid, e.g. after a failed form validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `invalid` - Whether the widget is invalid.
+    pub fn set_invalid(&mut self, invalid: bool) {
+        self.invalid = invalid;
+    }
+
+    /// Returns whether the widget is drawn as invalid, either because of
+    /// [`Self::set_invalid`] or the check set with
+    /// [`ChipEditBuilder::invalid_if`](crate::ChipEditBuilder::invalid_if).
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts backing the widget.
+    pub fn is_invalid(&self, texts: &[String]) -> bool {
+        self.invalid || self.invalid_if.as_ref().is_some_and(|check| check(texts))
+    }
+
+    /// Returns the label shown instead of the text of the chip at `index`.
+    pub fn label(&self, index: usize) -> Option<&str> {
+        self.units.get(index * 2 + 1)?.label.as_deref()
+    }
+
+    /// Shows `label` instead of the text of the chip at `index` while it is
+    /// not edited.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    /// * `label` - The label, or `None` to show the text.
+    pub fn set_label(&mut self, index: usize, label: Option<String>) {
+        if let Some(chip) = self.units.get_mut(index * 2 + 1) {
+            chip.label = label;
+        }
+    }
+
+    /// Overrides the colors of the chip at `index`, e.g. to highlight it.
+    ///
+    /// The colors stay with the chip while other chips are added or removed.
+    /// They are replaced every frame if
+    /// [`ChipEditBuilder::chip_color_fn`](crate::ChipEditBuilder::chip_color_fn)
+    /// is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    /// * `bg_color` - The background color of the chip.
+    /// * `text_color` - The text color of the chip.
+    pub fn set_chip_colors(&mut self, index: usize, bg_color: Color32, text_color: Color32) {
+        if let Some(chip) = self.units.get_mut(index * 2 + 1) {
+            chip.style.bg_color = Some(bg_color);
+            chip.style.text_color = Some(text_color);
+        }
+    }
+
+    /// Sets the leading icon of the chip at `index`, e.g. the avatar of a
+    /// recipient.
+    ///
+    /// The icon is replaced every frame if
+    /// [`ChipEditBuilder::icon_fn`](crate::ChipEditBuilder::icon_fn) is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    /// * `icon` - The icon, or `None` for no icon.
+    pub fn set_icon(&mut self, index: usize, icon: Option<ChipIcon>) {
+        if let Some(chip) = self.units.get_mut(index * 2 + 1) {
+            chip.style.icon = icon;
+        }
+    }
+
+    /// Sets the leading image of the chip at `index`, e.g. the avatar of a
+    /// contact, shown instead of its icon.
+    ///
+    /// The image is replaced every frame if
+    /// [`ChipEditBuilder::image_fn`](crate::ChipEditBuilder::image_fn) is
+    /// set. Images are not serialized.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    /// * `image` - The image, or `None` for no image.
+    pub fn set_image(&mut self, index: usize, image: Option<ImageSource<'static>>) {
+        if let Some(chip) = self.units.get_mut(index * 2 + 1) {
+            chip.image = image;
+        }
+    }
+
+    /// Returns the app data attached to the chip at `index`.
+    pub fn metadata(&self, index: usize) -> Option<&str> {
+        self.units.get(index * 2 + 1)?.metadata.as_deref()
+    }
+
+    /// Attaches app data to the chip at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    /// * `metadata` - The data, or `None` to remove it.
+    pub fn set_metadata(&mut self, index: usize, metadata: Option<String>) {
+        if let Some(chip) = self.units.get_mut(index * 2 + 1) {
+            chip.metadata = metadata;
+        }
+    }
+
+    /// Returns the app data attached to the chip at `index` with
+    /// [`Self::set_typed_metadata`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChipEditError::InvalidMetadata`] if the metadata is not a
+    /// `T`.
+    #[cfg(feature = "typed-metadata")]
+    pub fn typed_metadata<T: serde::de::DeserializeOwned>(
+        &self,
+        index: usize,
+    ) -> Result<Option<T>, ChipEditError> {
+        self.metadata(index)
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|err| ChipEditError::InvalidMetadata(err.to_string()))
+    }
+
+    /// Attaches app data of any serde type to the chip at `index`. It is
+    /// stored as JSON in the [`Self::metadata`] of the chip, so that it is
+    /// persisted with the widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`.
+    /// * `metadata` - The data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChipEditError::InvalidMetadata`] if the data cannot be
+    /// serialized.
+    #[cfg(feature = "typed-metadata")]
+    pub fn set_typed_metadata<T: serde::Serialize>(
+        &mut self,
+        index: usize,
+        metadata: &T,
+    ) -> Result<(), ChipEditError> {
+        let metadata = serde_json::to_string(metadata)
+            .map_err(|err| ChipEditError::InvalidMetadata(err.to_string()))?;
+        self.set_metadata(index, Some(metadata));
+        Ok(())
+    }
+
+    /// Sets the values offered in a popup while entering chips.
+    ///
+    /// # Arguments
+    ///
+    /// * `suggestions` - An iterator of the values to suggest.
+    pub fn set_suggestions(&mut self, suggestions: impl IntoIterator<Item = impl ToString>) {
+        self.suggestions.items = suggestions.into_iter().map(|s| s.to_string()).collect();
+        self.suggestions.close();
+    }
+
+    /// Sets the scores by which suggestions are ordered, e.g. how often or how
+    /// recently each value was used in the app.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - Pairs of a value and its score, higher scores first.
+    pub fn set_suggestion_weights(
+        &mut self,
+        weights: impl IntoIterator<Item = (impl ToString, f32)>,
+    ) {
+        self.suggestions.weights = weights
+            .into_iter()
+            .map(|(item, weight)| (item.to_string(), weight))
+            .collect();
+    }
+
+    /// Moves keyboard focus to the chip at `index` on the next `show`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the chip in `texts`. Passing `texts.len()`
+    ///   (or anything larger) focuses the trailing input.
+    pub fn focus_chip(&mut self, index: usize) {
+        let unit = index.saturating_mul(2).saturating_add(1);
+        self.focused = Some(unit.min(self.units.len() - 1));
+        self.pending_surrender = false;
+    }
+
+    /// Removes keyboard focus from the widget on the next `show`, which deals
+    /// with text still being typed as the [`BlurPolicy`] says.
+    pub fn surrender_focus(&mut self) {
+        self.focused = None;
+        self.pending_surrender = true;
+    }
+
+    /// Returns the position of the focused chip in `texts`.
+    ///
+    /// Returns `None` when the focus is elsewhere in the ui or in the gap
+    /// between chips.
+    pub fn focused_index(&self) -> Option<usize> {
+        self.focused
+            .filter(|unit| unit % 2 == 1)
+            .map(|unit| unit / 2)
+    }
+
+    /// Commits the text of the unit at `unit` and turns the resulting pieces
+    /// into chips.
+    ///
+    /// Text typed in a gap becomes new chips in its place. A chip keeps the
+    /// first piece, and the remaining pieces become new chips after it.
+    fn split(&mut self, texts: &mut Vec<String>, unit: usize) {
+        let index = unit / 2;
+        let (pieces, at) = if self.units[unit].is_separator() {
+            let text = std::mem::take(&mut self.separator_text[index]);
+            (self.commit(&text), index)
+        } else {
+            self.units[unit].typing = false;
+            let mut pieces = self.commit(&texts[index]).into_iter();
+            let first = pieces.next().unwrap_or_default();
+            let old = std::mem::replace(&mut texts[index], first.text);
+            self.events
+                .extend(ChipEvent::edit(index, old, &texts[index]));
+            self.units[unit].label = first.label;
+            self.units[unit].metadata = first.metadata;
+            (pieces.collect(), index + 1)
+        };
+        self.insert_chips(texts, at, pieces);
+    }
+
+    /// Appends the text of the later of the chips at units `a` and `b` to the
+    /// earlier one and removes the later chip.
+    ///
+    /// Returns `false` if there is no later chip to merge.
+    fn merge(&mut self, texts: &mut Vec<String>, (a, b): (usize, usize)) -> bool {
+        let (keep, drop) = (a.min(b), a.max(b));
+        if self.units.get(drop).is_none_or(|unit| unit.is_separator()) {
+            return false;
+        }
+        let text = self.remove(texts, drop / 2);
+        let old = texts[keep / 2].clone();
+        texts[keep / 2].push_str(&text);
+        self.events
+            .extend(ChipEvent::edit(keep / 2, old, &texts[keep / 2]));
+        true
+    }
+
+    /// Displays the `UnownedChipEdit` widget in the given UI and returns the
+    /// response.
+    ///
+    /// # Arguments
+    ///
+    /// * `ui` - The UI to display the widget in.
+    /// * `texts` - A mutable reference to a vector of strings representing the
+    ///   texts for the chips.
+    ///
+    /// # Returns
+    ///
+    /// A `Response` object representing the result of the widget interaction.
+    pub fn ui(&mut self, ui: &mut Ui, texts: &mut Vec<String>) -> Response {
+        self.show(ui, texts).response
+    }
+
+    /// Returns a [`Widget`] showing this `UnownedChipEdit` for `texts`, so it
+    /// can be used with helpers like [`Ui::add_sized`].
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - A mutable reference to a vector of strings representing the
+    ///   texts for the chips.
+    pub fn widget<'a>(&'a mut self, texts: &'a mut Vec<String>) -> impl Widget + 'a {
+        move |ui: &mut Ui| self.show(ui, texts).response
+    }
+
+    /// Returns the default background color for the widget
+    pub fn default_widget_bg_color(ui: &Ui) -> Color32 {
+        ui.visuals().extreme_bg_color
+    }
+
+    /// Returns the default foreground color for the widget
+    pub fn default_widget_fg_color(ui: &Ui) -> Color32 {
+        ui.visuals().selection.stroke.color
+    }
+}
+
+/// Returns the byte offset of the word starting with `prefix`, e.g. `#rust`,
+/// that `text` ends with if a whitespace was just typed after it.
+fn hashtag_at_end(text: &str, prefix: &str) -> Option<usize> {
+    let word = text.strip_suffix(char::is_whitespace)?;
+    let start = word
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace())
+        .map_or(0, |(at, c)| at + c.len_utf8());
+    word[start..]
+        .strip_prefix(prefix)
+        .filter(|tag| !tag.is_empty() && !tag.starts_with(prefix))
+        .map(|_| start)
+}
+
+/// Returns the digit typed with `key`, if it is a number key.
+fn digit(key: Key) -> Option<usize> {
+    let digit = match key {
+        Key::Num0 => 0,
+        Key::Num1 => 1,
+        Key::Num2 => 2,
+        Key::Num3 => 3,
+        Key::Num4 => 4,
+        Key::Num5 => 5,
+        Key::Num6 => 6,
+        Key::Num7 => 7,
+        Key::Num8 => 8,
+        Key::Num9 => 9,
+        _ => return None,
+    };
+    Some(digit)
+}
+
+/// Returns the lowercase letter or digit typed with `key`, if any.
+fn key_char(key: Key) -> Option<char> {
+    let mut chars = key.name().chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphanumeric() => Some(c.to_ascii_lowercase()),
+        _ => None,
+    }
+}
+
+/// Returns the character `key` types, if it is a punctuation or space key
+/// that can commit a chip.
+fn typed_char(key: Key) -> Option<char> {
+    match key {
+        Key::Space => Some(' '),
+        Key::Comma => Some(','),
+        Key::Semicolon => Some(';'),
+        Key::Period => Some('.'),
+        Key::Slash => Some('/'),
+        Key::Minus => Some('-'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::CentralPanel;
+    use egui::Context;
+    use egui::PointerButton;
+    use egui::Pos2;
+    use egui::RawInput;
+    use egui::pos2;
+
+    use super::*;
+    use crate::ChipEditBuilder;
+
+    /// Applies `events` to `rows` as described by [`ChipEvent`].
+    fn apply(rows: &mut Vec<String>, events: &[ChipEvent]) {
+        for event in events {
+            match event {
+                ChipEvent::Added { index, text } => rows.insert(*index, text.clone()),
+                ChipEvent::Removed { index, .. } => {
+                    rows.remove(*index);
+                }
+                ChipEvent::Edited { index, new, .. } => rows[*index] = new.clone(),
+                ChipEvent::Reordered { from, to } => {
+                    let row = rows.remove(*from);
+                    rows.insert(*to, row);
+                }
+            }
+        }
+    }
+
+    /// Runs `edit` on a widget showing `texts` and checks that replaying the
+    /// reported events on `texts` gives the chips after the edit.
+    fn assert_replays(texts: &[&str], edit: impl FnOnce(&mut UnownedChipEdit, &mut Vec<String>)) {
+        let mut widget = UnownedChipEdit::new(",").unwrap();
+        let mut texts: Vec<String> = texts.iter().map(|text| text.to_string()).collect();
+        widget.sync(&texts);
+        let mut rows = texts.clone();
+        edit(&mut widget, &mut texts);
+        apply(&mut rows, &widget.events);
+        assert_eq!(rows, texts);
+    }
+
+    #[test]
+    fn split_chip_replays() {
+        assert_replays(&["a", "b,c,d"], |widget, texts| widget.split(texts, 3));
+    }
+
+    #[test]
+    fn split_gap_replays() {
+        assert_replays(&["a", "b"], |widget, texts| {
+            widget.separator_text[1] = "c,d".to_owned();
+            widget.split(texts, 2);
+        });
+    }
+
+    #[test]
+    fn merge_replays() {
+        assert_replays(&["a", "b", "c"], |widget, texts| {
+            assert!(widget.merge(texts, (3, 1)));
+        });
+    }
+
+    #[test]
+    fn remove_replays() {
+        assert_replays(&["a", "b", "c"], |widget, texts| {
+            widget.remove(texts, 1);
+        });
+    }
+
+    #[test]
+    fn sort_replays() {
+        assert_replays(&["d", "b", "c", "a"], |widget, texts| {
+            assert!(widget.sort_chips(texts, ChipSort::Alphabetical));
+        });
+    }
+
+    #[test]
+    fn swap_replays() {
+        assert_replays(&["a", "b", "c", "d"], |widget, texts| {
+            widget.swap(texts, 0, 2)
+        });
+    }
+
+    /// Shows `widget` for `texts` in a frame of `ctx` with `events` as the
+    /// input.
+    fn run_frame(
+        ctx: &Context,
+        widget: &mut UnownedChipEdit,
+        texts: &mut Vec<String>,
+        events: Vec<Event>,
+    ) -> ChipEditOutput {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let mut output = None;
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| output = Some(widget.show(ui, texts)));
+        });
+        output.unwrap()
+    }
+
+    /// Returns a press of `key` without modifiers.
+    fn press(key: Key) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    /// Returns the input of typing `text`.
+    fn typed(text: &str) -> Vec<Event> {
+        vec![Event::Text(text.to_owned())]
+    }
+
+    /// Returns the input of a primary click at `pos`.
+    fn click_at(pos: Pos2) -> Vec<Event> {
+        let button = |pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        vec![Event::PointerMoved(pos), button(true), button(false)]
+    }
+
+    /// Returns a widget showing `texts` with the focus in the editor of the
+    /// unit at `unit`, after the frames that move the focus there.
+    fn focused_widget(
+        ctx: &Context,
+        builder: ChipEditBuilder,
+        texts: &[&str],
+        unit: usize,
+    ) -> (UnownedChipEdit, Vec<String>) {
+        let mut widget = builder.id_salt("chips").build_unowned();
+        let mut texts = texts.iter().map(|text| text.to_string()).collect();
+        widget.focused = Some(unit);
+        run_frame(ctx, &mut widget, &mut texts, vec![]);
+        run_frame(ctx, &mut widget, &mut texts, vec![]);
+        assert_eq!(widget.focused, Some(unit));
+        (widget, texts)
+    }
+
+    #[test]
+    fn typing_a_separator_in_a_chip_splits_it() {
+        let ctx = Context::default();
+        let (mut widget, mut texts) =
+            focused_widget(&ctx, ChipEditBuilder::new(",").unwrap(), &["ab"], 1);
+        run_frame(&ctx, &mut widget, &mut texts, typed(",c"));
+        assert_eq!(texts, ["ab", "c"]);
+    }
+
+    #[test]
+    fn backspace_at_the_start_of_a_chip_merges_it() {
+        let ctx = Context::default();
+        let (mut widget, mut texts) =
+            focused_widget(&ctx, ChipEditBuilder::new(",").unwrap(), &["a", "b"], 3);
+        run_frame(&ctx, &mut widget, &mut texts, vec![press(Key::Home)]);
+        run_frame(&ctx, &mut widget, &mut texts, vec![press(Key::Backspace)]);
+        assert_eq!(texts, ["ab"]);
+    }
+
+    #[test]
+    fn blur_policy_decides_what_blurring_keeps() {
+        let cases = [
+            (
+                BlurPolicy::CommitOnBlur,
+                ["ab"].as_slice(),
+                ["a", "c"].as_slice(),
+            ),
+            (
+                BlurPolicy::KeepEditing,
+                ["ab"].as_slice(),
+                ["a", "c"].as_slice(),
+            ),
+            (
+                BlurPolicy::DiscardOnBlur,
+                ["a"].as_slice(),
+                ["a"].as_slice(),
+            ),
+        ];
+        for (policy, edited, started) in cases {
+            // The focus moves to another widget, or the app takes it away.
+            for by_app in [false, true] {
+                let blur = |ctx: &Context, widget: &mut UnownedChipEdit, texts: &mut Vec<_>| {
+                    if by_app {
+                        widget.surrender_focus();
+                        run_frame(ctx, widget, texts, vec![]);
+                    } else {
+                        run_frame(ctx, widget, texts, click_at(pos2(400., 400.)));
+                    }
+                };
+                let ctx = Context::default();
+                let builder = ChipEditBuilder::new(",").unwrap().blur_policy(policy);
+                let (mut widget, mut texts) = focused_widget(&ctx, builder, &["a"], 1);
+                run_frame(&ctx, &mut widget, &mut texts, typed("b"));
+                blur(&ctx, &mut widget, &mut texts);
+                assert_eq!(texts, edited, "{policy:?}, by app: {by_app}");
+
+                let ctx = Context::default();
+                let builder = ChipEditBuilder::new(",").unwrap().blur_policy(policy);
+                let (mut widget, mut texts) = focused_widget(&ctx, builder, &["a"], 2);
+                run_frame(&ctx, &mut widget, &mut texts, typed("c"));
+                run_frame(&ctx, &mut widget, &mut texts, vec![]);
+                blur(&ctx, &mut widget, &mut texts);
+                assert_eq!(texts, started, "{policy:?}, by app: {by_app}");
+                assert!(
+                    widget.units.iter().all(|unit| !unit.typing),
+                    "{policy:?}, by app: {by_app}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tab_commits_the_chip_being_typed() {
+        let ctx = Context::default();
+        let builder = ChipEditBuilder::new(",").unwrap().commit_keys(&[Key::Tab]);
+        let (mut widget, mut texts) = focused_widget(&ctx, builder, &[], 0);
+        run_frame(&ctx, &mut widget, &mut texts, typed("a"));
+        run_frame(&ctx, &mut widget, &mut texts, vec![]);
+        run_frame(&ctx, &mut widget, &mut texts, vec![press(Key::Tab)]);
+        run_frame(&ctx, &mut widget, &mut texts, vec![]);
+        assert_eq!(texts, ["a"]);
+        assert!(!widget.units[1].typing);
+        assert_eq!(widget.focused, Some(2));
+    }
+
+    #[test]
+    fn escape_reverts_an_edit() {
+        let ctx = Context::default();
+        let (mut widget, mut texts) =
+            focused_widget(&ctx, ChipEditBuilder::new(",").unwrap(), &["a"], 1);
+        run_frame(&ctx, &mut widget, &mut texts, typed("b"));
+        assert_eq!(texts, ["ab"]);
+        run_frame(&ctx, &mut widget, &mut texts, vec![press(Key::Escape)]);
+        assert_eq!(texts, ["a"]);
+    }
+
+    #[test]
+    fn escape_drops_the_chip_being_typed() {
+        let ctx = Context::default();
+        let (mut widget, mut texts) =
+            focused_widget(&ctx, ChipEditBuilder::new(",").unwrap(), &["a"], 2);
+        run_frame(&ctx, &mut widget, &mut texts, typed("b"));
+        run_frame(&ctx, &mut widget, &mut texts, vec![]);
+        assert_eq!(texts, ["a", "b"]);
+        run_frame(&ctx, &mut widget, &mut texts, vec![press(Key::Escape)]);
+        assert_eq!(texts, ["a"]);
+    }
+
+    #[test]
+    fn delete_removes_the_selected_chips() {
+        let ctx = Context::default();
+        let mut widget = ChipEditBuilder::new(",")
+            .unwrap()
+            .multi_select(true)
+            .build_unowned();
+        let mut texts = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+        run_frame(&ctx, &mut widget, &mut texts, vec![]);
+        widget.set_selected(0, true);
+        widget.set_selected(2, true);
+        let output = run_frame(&ctx, &mut widget, &mut texts, vec![press(Key::Delete)]);
+        assert_eq!(texts, ["b"]);
+        assert!(output.response.changed());
+    }
+
+    #[test]
+    fn a_separator_between_quotes_is_kept() {
+        let ctx = Context::default();
+        let (mut widget, mut texts) =
+            focused_widget(&ctx, ChipEditBuilder::search_filters(), &["subject:"], 1);
+        let typed = Event::Text(r#""q3 plan"#.to_owned());
+        run_frame(&ctx, &mut widget, &mut texts, vec![typed]);
+        assert_eq!(texts, [r#"subject:"q3 plan"#]);
+        let typed = Event::Text(r#"" label:rust"#.to_owned());
+        run_frame(&ctx, &mut widget, &mut texts, vec![typed]);
+        assert_eq!(texts, [r#"subject:"q3 plan""#, "label:rust"]);
+    }
+
+    #[test]
+    fn enter_accepts_the_highlighted_suggestion() {
+        let ctx = Context::default();
+        let builder = ChipEditBuilder::new(",")
+            .unwrap()
+            .suggestions(["rust", "ruby"]);
+        let (mut widget, mut texts) = focused_widget(&ctx, builder, &[], 0);
+        run_frame(&ctx, &mut widget, &mut texts, typed("ru"));
+        run_frame(&ctx, &mut widget, &mut texts, vec![]);
+        run_frame(&ctx, &mut widget, &mut texts, typed("b"));
+        assert_eq!(widget.suggestions.anchor, Some(1));
+        run_frame(&ctx, &mut widget, &mut texts, vec![press(Key::Enter)]);
+        assert_eq!(texts, ["ruby"]);
+        assert_eq!(widget.suggestions.anchor, None);
+        assert!(widget.units.iter().all(|unit| !unit.typing));
+    }
+
+    #[test]
+    fn text_typed_on_the_entry_is_rewritten_and_split() {
+        let ctx = Context::default();
+        let mut widget = ChipEditBuilder::new(",")
+            .unwrap()
+            .id_salt("tags")
+            .typed_separators([';'])
+            .char_filter(char::is_alphanumeric)
+            .build_unowned();
+        let mut texts = Vec::new();
+        run_frame(&ctx, &mut widget, &mut texts, vec![]);
+        ctx.memory_mut(|m| m.request_focus(Id::new("tags").with("entry")));
+        run_frame(&ctx, &mut widget, &mut texts, vec![]);
+        assert!(widget.entry_focused);
+        run_frame(&ctx, &mut widget, &mut texts, typed("a!;b"));
+        assert_eq!(texts, ["a", "b"]);
+        assert_eq!(widget.focused, Some(4));
+    }
+
+    /// Shows `widget` for `texts` in a window 200 points wide at `time`, with
+    /// `events` as the input.
+    fn narrow_frame(
+        ctx: &Context,
+        widget: &mut UnownedChipEdit,
+        texts: &mut Vec<String>,
+        time: f64,
+        events: Vec<Event>,
+    ) {
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(200., 100.))),
+            time: Some(time),
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| widget.show(ui, texts));
+        });
+    }
+
+    #[test]
+    fn single_row_scrolls_with_drag_inertia_and_wheel() {
+        let mut texts: Vec<String> = (0..20).map(|n| format!("chip {n}")).collect();
+        let mut widget = ChipEditBuilder::new(",")
+            .unwrap()
+            .single_row(true)
+            .build_unowned();
+        let ctx = Context::default();
+        let mut time = 0.;
+        // Returns where the first chip starts after a frame with `events`.
+        let mut frame = |events| {
+            time += 1. / 60.;
+            narrow_frame(&ctx, &mut widget, &mut texts, time, events);
+            widget.chip_rect(0).unwrap().min.x
+        };
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        // The row learns that it overflows during the first frame.
+        frame(vec![]);
+        let start = frame(vec![]);
+
+        // A flick to the left keeps the row moving after the release.
+        let grab = pos2(100., 20.);
+        frame(vec![Event::PointerMoved(grab), button(grab, true)]);
+        for step in 1..=5 {
+            frame(vec![Event::PointerMoved(
+                grab - vec2(20. * step as f32, 0.),
+            )]);
+        }
+        let released = frame(vec![button(grab - vec2(100., 0.), false)]);
+        assert!(released < start - 50.);
+        assert!(frame(vec![]) < released);
+        for _ in 0..120 {
+            frame(vec![]);
+        }
+
+        // A vertical wheel scrolls the row sideways.
+        let wheel = Event::MouseWheel {
+            unit: egui::MouseWheelUnit::Point,
+            delta: vec2(0., -40.),
+            modifiers: Modifiers::NONE,
+        };
+        let stopped = frame(vec![]);
+        frame(vec![Event::PointerMoved(grab), wheel]);
+        for _ in 0..30 {
+            frame(vec![]);
+        }
+        assert!(frame(vec![]) < stopped);
+    }
+
+    #[test]
+    fn saved_sort_round_trips() {
+        let saved = |sort| {
+            let widget = ChipEditBuilder::new(",")
+                .unwrap()
+                .sort(sort)
+                .build_unowned();
+            let json = serde_json::to_string(&widget).unwrap();
+            serde_json::from_str::<UnownedChipEdit>(&json).unwrap().sort
+        };
+        assert!(matches!(
+            saved(ChipSort::ByLength),
+            Some(ChipSort::ByLength)
+        ));
+        assert!(matches!(
+            saved(ChipSort::Alphabetical),
+            Some(ChipSort::Alphabetical)
+        ));
+        assert!(saved(ChipSort::Custom(|a, b| b.cmp(a))).is_none());
+    }
+
+    #[test]
+    fn hashtag_is_found_once_finished() {
+        assert_eq!(hashtag_at_end("fix #rust ", "#"), Some(4));
+        assert_eq!(hashtag_at_end("#rust\n", "#"), Some(0));
+        assert_eq!(hashtag_at_end("fix #rust", "#"), None);
+        assert_eq!(hashtag_at_end("fix # ", "#"), None);
+        assert_eq!(hashtag_at_end("fix ##rust ", "#"), None);
     }
 }